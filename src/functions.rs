@@ -1,7 +1,9 @@
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::hash::Hash;
-use std::thread::sleep;
+use std::cell::{Cell, RefCell};
+use std::collections::hash_map::RandomState;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::{mpsc, Arc, Condvar, Mutex, PoisonError};
+use std::thread::{self, sleep, JoinHandle};
 use std::time::{Duration, Instant};
 
 /// Combine a vector of Results into a single Result containing a vector of Ok values.
@@ -17,6 +19,40 @@ pub fn combine_results<T, E>(results: Vec<Result<T, E>>) -> Result<Vec<T>, E> {
     results.into_iter().collect()
 }
 
+/// Apply a fallible function to every element of a vector, stopping at the first error.
+///
+/// # Arguments
+///
+/// * `vec` - The vector to be transformed.
+/// * `f` - The fallible function applied to each element.
+///
+/// # Returns
+///
+/// A Result containing all mapped values if every call succeeds, otherwise the first Err.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::try_map;
+///
+/// let parsed: Result<Vec<i32>, _> = try_map(vec!["1", "2", "3"], str::parse::<i32>);
+/// assert_eq!(parsed, Ok(vec![1, 2, 3]));
+///
+/// let mut calls = 0;
+/// let result = try_map(vec![1, 2, 3, 4], |x| {
+///     calls += 1;
+///     if x == 2 { Err(format!("bad value {}", x)) } else { Ok(x * 10) }
+/// });
+/// assert_eq!(result, Err("bad value 2".to_string()));
+/// assert_eq!(calls, 2);
+/// ```
+pub fn try_map<T, U, E, F>(vec: Vec<T>, f: F) -> Result<Vec<U>, E>
+where
+    F: FnMut(T) -> Result<U, E>,
+{
+    vec.into_iter().map(f).collect()
+}
+
 /// Unwrap an Option or provide a default value if it is None.
 ///
 /// # Arguments
@@ -39,8 +75,8 @@ pub fn unwrap_or_default<T: Default>(option: Option<T>) -> T {
 /// # Returns
 ///
 /// A new vector containing cloned elements.
-pub fn deep_clone_vec<T: Clone>(vec: &Vec<T>) -> Vec<T> {
-    vec.iter().cloned().collect()
+pub fn deep_clone_vec<T: Clone>(vec: &[T]) -> Vec<T> {
+    vec.to_vec()
 }
 
 /// Apply a function to each element of a vector along with its index.
@@ -73,6 +109,36 @@ pub fn flatten_results<T, E>(results: Vec<Result<T, E>>) -> Result<Vec<T>, E> {
     results.into_iter().collect()
 }
 
+/// Split a vector of Results into its Ok values and its Err values.
+///
+/// # Arguments
+///
+/// * `results` - A vector of Results to be split.
+///
+/// # Returns
+///
+/// A tuple of every Ok value and every Err value, each in their original order.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::partition_results;
+///
+/// let results = vec![Ok(1), Err("a"), Ok(2), Err("b"), Ok(3)];
+/// assert_eq!(partition_results(results), (vec![1, 2, 3], vec!["a", "b"]));
+/// ```
+pub fn partition_results<T, E>(results: Vec<Result<T, E>>) -> (Vec<T>, Vec<E>) {
+    let mut oks = Vec::new();
+    let mut errs = Vec::new();
+    for result in results {
+        match result {
+            Ok(value) => oks.push(value),
+            Err(err) => errs.push(err),
+        }
+    }
+    (oks, errs)
+}
+
 /// Chain multiple Option operations into a single Option.
 ///
 /// # Arguments
@@ -82,10 +148,64 @@ pub fn flatten_results<T, E>(results: Vec<Result<T, E>>) -> Result<Vec<T>, E> {
 /// # Returns
 ///
 /// An Option containing a vector of inner values if all options are Some, otherwise None.
+/// This is the inverse of `transpose_option`.
 pub fn chain_options<T>(options: Vec<Option<T>>) -> Option<Vec<T>> {
     options.into_iter().collect()
 }
 
+/// Split an optional vector into a vector of Options.
+///
+/// # Arguments
+///
+/// * `opt` - The optional vector to be split.
+///
+/// # Returns
+///
+/// Each element wrapped in Some if `opt` is Some, otherwise an empty vector. `chain_options`
+/// reverses this for Some, but turns the empty vector from None into `Some(vec![])`.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::{chain_options, transpose_option};
+///
+/// assert_eq!(transpose_option(Some(vec![1, 2, 3])), vec![Some(1), Some(2), Some(3)]);
+/// assert_eq!(transpose_option::<i32>(None), vec![]);
+///
+/// let values = Some(vec!["a", "b"]);
+/// assert_eq!(chain_options(transpose_option(values.clone())), values);
+/// ```
+pub fn transpose_option<T>(opt: Option<Vec<T>>) -> Vec<Option<T>> {
+    opt.into_iter().flatten().map(Some).collect()
+}
+
+/// Find the positions of every None in a slice of Options.
+///
+/// # Arguments
+///
+/// * `options` - The Options to inspect.
+///
+/// # Returns
+///
+/// The indices of all None values, in ascending order.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::indices_of_none;
+///
+/// assert_eq!(indices_of_none(&[Some(1), None, Some(3), None]), vec![1, 3]);
+/// assert!(indices_of_none(&[Some(1), Some(2)]).is_empty());
+/// ```
+pub fn indices_of_none<T>(options: &[Option<T>]) -> Vec<usize> {
+    options
+        .iter()
+        .enumerate()
+        .filter(|(_, option)| option.is_none())
+        .map(|(index, _)| index)
+        .collect()
+}
+
 /// Filter and map elements of a vector based on a provided function.
 ///
 /// # Arguments
@@ -103,18 +223,188 @@ where
     vec.into_iter().filter_map(f).collect()
 }
 
-/// Perform a debounced action after a specified duration.
+/// Filter and map elements of a vector with a fallible function, stopping at the first error.
+///
+/// # Arguments
+///
+/// * `vec` - The vector to be filtered and mapped.
+/// * `f` - The function to apply to each element; `Ok(None)` skips the element.
+///
+/// # Returns
+///
+/// A Result containing the kept mapped elements, or the first Err returned by `f`.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::try_filter_map;
+///
+/// let parse_non_empty = |s: &str| {
+///     if s.is_empty() {
+///         Ok(None)
+///     } else {
+///         s.parse::<i32>().map(Some)
+///     }
+/// };
+///
+/// assert_eq!(try_filter_map(vec!["1", "", "3"], parse_non_empty), Ok(vec![1, 3]));
+/// assert!(try_filter_map(vec!["1", "x", ""], parse_non_empty).is_err());
+/// ```
+pub fn try_filter_map<T, U, E, F>(vec: Vec<T>, f: F) -> Result<Vec<U>, E>
+where
+    F: Fn(T) -> Result<Option<U>, E>,
+{
+    vec.into_iter()
+        .filter_map(|item| f(item).transpose())
+        .collect()
+}
+
+/// Create a trailing-edge debouncer for an action.
 ///
 /// # Arguments
 ///
 /// * `action` - The action to be debounced.
-/// * `duration` - The duration to wait before executing the debounced action.
-pub fn debounce<F>(action: F, duration: Duration)
+/// * `duration` - The quiet period required after the last call before the action runs.
+///
+/// # Returns
+///
+/// A `Debouncer` wrapping the action.
+pub fn debounce<F>(action: F, duration: Duration) -> Debouncer
 where
-    F: Fn(),
+    F: Fn() + Send + 'static,
 {
-    sleep(duration);
-    action();
+    Debouncer::new(duration, action)
+}
+
+struct DebounceState {
+    deadline: Option<Instant>,
+    shutdown: bool,
+}
+
+/// Collapses rapid successive calls into a single trailing execution of an action.
+///
+/// Every `call` resets the timer; the action only runs once `duration` has passed
+/// without any further calls. A pending execution is discarded when the debouncer is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::Debouncer;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// let count = Arc::new(AtomicUsize::new(0));
+/// let counter = Arc::clone(&count);
+/// let debouncer = Debouncer::new(Duration::from_millis(200), move || {
+///     counter.fetch_add(1, Ordering::SeqCst);
+/// });
+///
+/// // The calls are far closer together than the window, so they collapse into one run.
+/// for _ in 0..5 {
+///     debouncer.call();
+/// }
+/// assert!(debouncer.is_pending());
+/// std::thread::sleep(Duration::from_millis(500));
+/// assert_eq!(count.load(Ordering::SeqCst), 1);
+///
+/// debouncer.call();
+/// debouncer.cancel();
+/// assert!(!debouncer.is_pending());
+/// std::thread::sleep(Duration::from_millis(500));
+/// assert_eq!(count.load(Ordering::SeqCst), 1);
+/// ```
+pub struct Debouncer {
+    shared: Arc<(Mutex<DebounceState>, Condvar)>,
+    duration: Duration,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl Debouncer {
+    /// Create a new debouncer backed by a background thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The quiet period required after the last call before the action runs.
+    /// * `action` - The action to be debounced.
+    ///
+    /// # Returns
+    ///
+    /// A new `Debouncer` with no pending execution.
+    pub fn new<F>(duration: Duration, action: F) -> Self
+    where
+        F: Fn() + Send + 'static,
+    {
+        let shared = Arc::new((
+            Mutex::new(DebounceState {
+                deadline: None,
+                shutdown: false,
+            }),
+            Condvar::new(),
+        ));
+        let worker_shared = Arc::clone(&shared);
+
+        let worker = thread::spawn(move || {
+            let (lock, cvar) = &*worker_shared;
+            let mut state = lock.lock().unwrap();
+            while !state.shutdown {
+                match state.deadline {
+                    None => state = cvar.wait(state).unwrap(),
+                    Some(deadline) => {
+                        let now = Instant::now();
+                        if now >= deadline {
+                            state.deadline = None;
+                            drop(state);
+                            action();
+                            state = lock.lock().unwrap();
+                        } else {
+                            state = cvar.wait_timeout(state, deadline - now).unwrap().0;
+                        }
+                    }
+                }
+            }
+        });
+
+        Debouncer {
+            shared,
+            duration,
+            worker: Some(worker),
+        }
+    }
+
+    /// Schedule the action, resetting the timer if an execution is already pending.
+    pub fn call(&self) {
+        let (lock, cvar) = &*self.shared;
+        lock.lock().unwrap().deadline = Some(Instant::now() + self.duration);
+        cvar.notify_one();
+    }
+
+    /// Drop any pending execution without running the action.
+    pub fn cancel(&self) {
+        let (lock, cvar) = &*self.shared;
+        lock.lock().unwrap().deadline = None;
+        cvar.notify_one();
+    }
+
+    /// Check whether an execution is currently scheduled.
+    ///
+    /// # Returns
+    ///
+    /// true if the action will run once the quiet period elapses, otherwise false.
+    pub fn is_pending(&self) -> bool {
+        self.shared.0.lock().unwrap().deadline.is_some()
+    }
+}
+
+impl Drop for Debouncer {
+    fn drop(&mut self) {
+        let (lock, cvar) = &*self.shared;
+        lock.lock().unwrap().shutdown = true;
+        cvar.notify_one();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
 }
 
 /// Memoize the results of a function using a cache.
@@ -132,21 +422,328 @@ where
     T: Eq + Hash + Clone,
     U: Clone,
 {
-    let cache: RefCell<HashMap<T, U>> = RefCell::new(HashMap::new());
+    let cache = MemoCache::new();
+    move |arg: T| -> U { cache.get_or_compute(arg, &func) }
+}
+
+/// A memoization cache that counts how many lookups were served from it.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::MemoCache;
+///
+/// let cache = MemoCache::new();
+/// let square = |x: u32| x * x;
+///
+/// assert_eq!(cache.get_or_compute(3, square), 9);
+/// assert_eq!(cache.get_or_compute(3, square), 9);
+/// assert_eq!(cache.get_or_compute(4, square), 16);
+/// assert_eq!(cache.get_or_compute(3, square), 9);
+///
+/// assert_eq!(cache.hits(), 2);
+/// assert_eq!(cache.misses(), 2);
+/// assert_eq!(cache.len(), 2);
+/// ```
+#[derive(Debug)]
+pub struct MemoCache<T, U> {
+    map: RefCell<HashMap<T, U>>,
+    hits: Cell<usize>,
+    misses: Cell<usize>,
+}
+
+impl<T, U> MemoCache<T, U> {
+    /// Create a new, empty cache.
+    pub fn new() -> Self {
+        MemoCache {
+            map: RefCell::new(HashMap::new()),
+            hits: Cell::new(0),
+            misses: Cell::new(0),
+        }
+    }
+
+    /// The number of lookups answered from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits.get()
+    }
+
+    /// The number of lookups that had to compute their result.
+    pub fn misses(&self) -> usize {
+        self.misses.get()
+    }
+
+    /// The number of cached results.
+    pub fn len(&self) -> usize {
+        self.map.borrow().len()
+    }
+
+    /// Whether the cache holds no results.
+    pub fn is_empty(&self) -> bool {
+        self.map.borrow().is_empty()
+    }
+}
+
+impl<T: Eq + Hash + Clone, U: Clone> MemoCache<T, U> {
+    /// Look up the cached result for `key`, computing and storing it with `f` on a miss.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The argument to look up.
+    /// * `f` - The function used to compute a missing result.
+    ///
+    /// # Returns
+    ///
+    /// The cached or newly computed result.
+    pub fn get_or_compute<F>(&self, key: T, f: F) -> U
+    where
+        F: FnOnce(T) -> U,
+    {
+        if let Some(result) = self.map.borrow().get(&key) {
+            self.hits.set(self.hits.get() + 1);
+            return result.clone();
+        }
+
+        self.misses.set(self.misses.get() + 1);
+        let result = f(key.clone());
+        self.map.borrow_mut().insert(key, result.clone());
+        result
+    }
+}
+
+impl<T, U> Default for MemoCache<T, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A key-value cache whose entries expire a fixed time after they are inserted.
+///
+/// Expired entries are hidden from `get` but stay in memory until `cleanup` is called or
+/// the key is inserted again.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::Cache;
+/// use std::thread::sleep;
+/// use std::time::Duration;
+///
+/// let mut cache = Cache::new(Duration::from_millis(200));
+/// cache.insert("token", 42);
+/// assert_eq!(cache.get(&"token"), Some(&42));
+/// assert_eq!(cache.get(&"missing"), None);
+///
+/// // Wait well past the TTL so the entry has certainly expired.
+/// sleep(Duration::from_millis(500));
+/// assert_eq!(cache.get(&"token"), None);
+/// assert_eq!(cache.len(), 1);
+///
+/// cache.cleanup();
+/// assert!(cache.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cache<K, V> {
+    ttl: Duration,
+    entries: HashMap<K, (V, Instant)>,
+}
+
+impl<K: Eq + Hash, V> Cache<K, V> {
+    /// Create a new, empty cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl` - How long each entry stays valid after it is inserted.
+    ///
+    /// # Returns
+    ///
+    /// A new `Cache` with the given time to live.
+    pub fn new(ttl: Duration) -> Self {
+        Cache {
+            ttl,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Insert a value, replacing any previous entry for the key and restarting its expiry.
+    pub fn insert(&mut self, key: K, value: V) {
+        self.entries.insert(key, (value, Instant::now()));
+    }
+
+    /// Look up a value that has not yet expired.
+    ///
+    /// # Returns
+    ///
+    /// The cached value, or None if the key is missing or its entry is older than the TTL.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries
+            .get(key)
+            .filter(|(_, inserted)| inserted.elapsed() < self.ttl)
+            .map(|(value, _)| value)
+    }
+
+    /// Remove every expired entry.
+    pub fn cleanup(&mut self) {
+        let ttl = self.ttl;
+        self.entries.retain(|_, (_, inserted)| inserted.elapsed() < ttl);
+    }
+
+    /// The number of stored entries, including expired ones not yet cleaned up.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Memoize the results of a function using a cache that can be shared across threads.
+///
+/// The function runs without the cache locked, so threads asking for the same uncached
+/// argument at once may each compute it; the first result stored wins. A panic inside
+/// `func` doesn't poison the cache for other callers.
+///
+/// # Arguments
+///
+/// * `func` - The function to be memoized.
+///
+/// # Returns
+///
+/// A new function with memoization that is `Send + Sync`.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::memoize_sync;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::thread;
+///
+/// let calls = AtomicUsize::new(0);
+/// let square = memoize_sync(|x: u64| {
+///     calls.fetch_add(1, Ordering::SeqCst);
+///     x * x
+/// });
+///
+/// thread::scope(|scope| {
+///     for _ in 0..8 {
+///         scope.spawn(|| {
+///             for x in 0..10 {
+///                 assert_eq!(square(x), x * x);
+///             }
+///         });
+///     }
+/// });
+///
+/// let computed = calls.load(Ordering::SeqCst);
+/// assert!((10..=80).contains(&computed));
+/// assert_eq!(square(3), 9);
+/// assert_eq!(calls.load(Ordering::SeqCst), computed);
+/// ```
+pub fn memoize_sync<T, U, F>(func: F) -> impl Fn(T) -> U + Send + Sync
+where
+    F: Fn(T) -> U + Send + Sync,
+    T: Eq + Hash + Clone + Send,
+    U: Clone + Send,
+{
+    let cache: Mutex<HashMap<T, U>> = Mutex::new(HashMap::new());
 
     move |arg: T| -> U {
-        if let Some(result) = cache.borrow().get(&arg) {
-            result.clone()
-        } else {
-            let result = func(arg.clone());
-            cache.borrow_mut().insert(arg, result.clone());
-            result
+        let cached = cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&arg)
+            .cloned();
+        if let Some(result) = cached {
+            return result;
+        }
+
+        let result = func(arg.clone());
+        cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(arg)
+            .or_insert(result)
+            .clone()
+    }
+}
+
+/// Memoize the results of a function using a cache bounded to `capacity` entries.
+///
+/// Every lookup or insertion marks the argument as most recently used. Once the cache
+/// holds more than `capacity` entries, the least recently used entry is evicted and will
+/// be recomputed the next time it is requested. A `capacity` of 0 disables caching.
+/// Recency is tracked in an ordered map, so each call costs O(log capacity) on top of hashing.
+///
+/// # Arguments
+///
+/// * `func` - The function to be memoized.
+/// * `capacity` - The maximum number of results kept in the cache.
+///
+/// # Returns
+///
+/// A new function with bounded memoization.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::memoize_lru;
+/// use std::cell::Cell;
+///
+/// let calls = Cell::new(0);
+/// let mut square = memoize_lru(|x: u32| { calls.set(calls.get() + 1); x * x }, 2);
+///
+/// assert_eq!(square(1), 1);
+/// assert_eq!(square(2), 4);
+/// assert_eq!(square(1), 1);
+/// assert_eq!(calls.get(), 2);
+///
+/// // 2 is now the least recently used entry and gets evicted.
+/// assert_eq!(square(3), 9);
+/// assert_eq!(square(2), 4);
+/// assert_eq!(calls.get(), 4);
+/// ```
+pub fn memoize_lru<T, U, F>(func: F, capacity: usize) -> impl FnMut(T) -> U
+where
+    F: Fn(T) -> U,
+    T: Eq + Hash + Clone,
+    U: Clone,
+{
+    // Each entry remembers the tick of its last use; `order` maps ticks back to keys, so the
+    // least recently used key is always the first one and a hit costs O(log capacity).
+    let mut cache: HashMap<T, (U, u64)> = HashMap::new();
+    let mut order: BTreeMap<u64, T> = BTreeMap::new();
+    let mut tick: u64 = 0;
+
+    move |arg: T| -> U {
+        tick += 1;
+        if let Some((result, last_used)) = cache.get_mut(&arg) {
+            let key = order.remove(last_used).unwrap_or(arg);
+            *last_used = tick;
+            order.insert(tick, key);
+            return result.clone();
+        }
+
+        let result = func(arg.clone());
+        if capacity == 0 {
+            return result;
         }
+
+        cache.insert(arg.clone(), (result.clone(), tick));
+        order.insert(tick, arg);
+        if cache.len() > capacity {
+            if let Some((_, oldest)) = order.pop_first() {
+                cache.remove(&oldest);
+            }
+        }
+        result
     }
 }
 
 /// Retry an action for a specified number of attempts with a delay between each attempt.
 ///
+/// The action is always attempted at least once, even if `max_attempts` is 0.
+///
 /// # Arguments
 ///
 /// * `action` - The action to be retried.
@@ -155,26 +752,309 @@ where
 ///
 /// # Returns
 ///
-/// Ok(()) if the action succeeds within the specified attempts, otherwise Err with the last encountered error.
-pub fn retry<F, E>(action: F, max_attempts: usize, delay: Duration) -> Result<(), E>
+/// Ok with the value of the first successful attempt, otherwise Err with the last encountered error.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::retry;
+/// use std::time::Duration;
+///
+/// assert_eq!(retry(|| Ok::<_, ()>(1), 3, Duration::ZERO), Ok(1));
+///
+/// let mut calls = 0;
+/// let result = retry(
+///     || {
+///         calls += 1;
+///         if calls < 3 { Err(calls) } else { Ok("done") }
+///     },
+///     5,
+///     Duration::ZERO,
+/// );
+/// assert_eq!(result, Ok("done"));
+/// assert_eq!(calls, 3);
+///
+/// let mut calls = 0;
+/// let result: Result<(), _> = retry(
+///     || {
+///         calls += 1;
+///         Err(calls)
+///     },
+///     4,
+///     Duration::ZERO,
+/// );
+/// assert_eq!(result, Err(4));
+/// ```
+pub fn retry<F, T, E>(mut action: F, max_attempts: usize, delay: Duration) -> Result<T, E>
 where
-    F: Fn() -> Result<(), E>,
-    E: std::fmt::Debug + Clone,
+    F: FnMut() -> Result<T, E>,
+    E: std::fmt::Debug,
 {
+    let max_attempts = max_attempts.max(1);
+    let mut last_error = None;
+
     for attempt in 1..=max_attempts {
         match action() {
-            Ok(_) => return Ok(()),
-            Err(ref err) => {
+            Ok(value) => return Ok(value),
+            Err(err) => {
                 eprintln!("Attempt {} failed: {:?}", attempt, err);
                 if attempt < max_attempts {
                     sleep(delay);
-                } else {
-                    return Err(err.clone());
                 }
+                last_error = Some(err);
+            }
+        }
+    }
+
+    Err(last_error.expect("at least one attempt is always made"))
+}
+
+/// Retry an async action for a specified number of attempts with a delay between each attempt.
+///
+/// Requires the `async` feature, which targets the tokio runtime for sleeping between attempts.
+/// The action is always attempted at least once, even if `max_attempts` is 0.
+///
+/// # Arguments
+///
+/// * `action` - A function producing the future to be retried.
+/// * `max_attempts` - The maximum number of attempts.
+/// * `delay` - The duration to wait between attempts.
+///
+/// # Returns
+///
+/// Ok with the value of the first successful attempt, otherwise Err with the last encountered error.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::retry_async;
+/// use std::time::Duration;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let mut calls = 0;
+/// let result = retry_async(
+///     || {
+///         calls += 1;
+///         let attempt = calls;
+///         async move { if attempt < 3 { Err(attempt) } else { Ok("done") } }
+///     },
+///     5,
+///     Duration::from_millis(1),
+/// )
+/// .await;
+/// assert_eq!(result, Ok("done"));
+/// assert_eq!(calls, 3);
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub async fn retry_async<F, Fut, T, E>(
+    mut action: F,
+    max_attempts: usize,
+    delay: Duration,
+) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, E>>,
+    E: std::fmt::Debug,
+{
+    let max_attempts = max_attempts.max(1);
+    let mut last_error = None;
+
+    for attempt in 1..=max_attempts {
+        match action().await {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                eprintln!("Attempt {} failed: {:?}", attempt, err);
+                if attempt < max_attempts {
+                    tokio::time::sleep(delay).await;
+                }
+                last_error = Some(err);
+            }
+        }
+    }
+
+    Err(last_error.expect("at least one attempt is always made"))
+}
+
+/// Computes a sequence of exponentially growing delays, optionally capped at a maximum.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::Backoff;
+/// use std::time::Duration;
+///
+/// let delays: Vec<_> = Backoff::new(Duration::from_millis(10), 2.0, None).take(4).collect();
+/// assert_eq!(delays, [10, 20, 40, 80].map(Duration::from_millis));
+///
+/// let capped: Vec<_> = Backoff::new(Duration::from_millis(10), 2.0, Some(Duration::from_millis(30)))
+///     .take(4)
+///     .collect();
+/// assert_eq!(capped, [10, 20, 30, 30].map(Duration::from_millis));
+///
+/// // Multipliers below 1.0 or not finite are treated as 1.0, so delays never shrink.
+/// let flat: Vec<_> = Backoff::new(Duration::from_millis(10), f64::NAN, None).take(3).collect();
+/// assert_eq!(flat, [10, 10, 10].map(Duration::from_millis));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    current: Duration,
+    multiplier: f64,
+    max_delay: Option<Duration>,
+}
+
+impl Backoff {
+    /// Create a new backoff sequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The first delay in the sequence.
+    /// * `multiplier` - The factor each delay is multiplied by to get the next one. Values below
+    ///   1.0, NaN and infinities are treated as 1.0.
+    /// * `max_delay` - An optional upper bound for every delay.
+    ///
+    /// # Returns
+    ///
+    /// A new `Backoff` starting at `base`.
+    pub fn new(base: Duration, multiplier: f64, max_delay: Option<Duration>) -> Self {
+        let multiplier = if multiplier.is_finite() {
+            multiplier.max(1.0)
+        } else {
+            1.0
+        };
+        Backoff {
+            current: base,
+            multiplier,
+            max_delay,
+        }
+    }
+
+    fn cap(&self, delay: Duration) -> Duration {
+        match self.max_delay {
+            Some(max_delay) => delay.min(max_delay),
+            None => delay,
+        }
+    }
+}
+
+impl Iterator for Backoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        let delay = self.cap(self.current);
+        let grown = Duration::try_from_secs_f64(self.current.as_secs_f64() * self.multiplier)
+            .unwrap_or(Duration::MAX);
+        self.current = self.cap(grown);
+        Some(delay)
+    }
+}
+
+/// Randomize a delay within +/- `jitter` of its value, where `jitter` is a fraction between 0 and 1.
+///
+/// The result never exceeds `max_delay`, and saturates at `Duration::MAX` instead of overflowing.
+fn apply_jitter(delay: Duration, jitter: f64, max_delay: Option<Duration>) -> Duration {
+    let jitter = jitter.clamp(0.0, 1.0);
+    if jitter == 0.0 {
+        return delay;
+    }
+
+    let random = RandomState::new().build_hasher().finish() as f64 / u64::MAX as f64;
+    let factor = 1.0 + jitter * (2.0 * random - 1.0);
+    let jittered =
+        Duration::try_from_secs_f64(delay.as_secs_f64() * factor).unwrap_or(Duration::MAX);
+    match max_delay {
+        Some(max_delay) => jittered.min(max_delay),
+        None => jittered,
+    }
+}
+
+/// Retry an action with exponentially growing, optionally jittered delays between attempts.
+///
+/// The action is always attempted at least once, even if `max_attempts` is 0.
+///
+/// # Arguments
+///
+/// * `action` - The action to be retried.
+/// * `max_attempts` - The maximum number of attempts.
+/// * `base` - The delay after the first failed attempt.
+/// * `multiplier` - The factor each delay is multiplied by after every failed attempt, treated
+///   as 1.0 if it is below 1.0 or not finite.
+/// * `max_delay` - An optional upper bound for the computed delay, applied after jitter too.
+/// * `jitter` - The fraction (0.0 to 1.0) by which each delay is randomly varied up or down.
+///
+/// # Returns
+///
+/// Ok with the value of the first successful attempt, otherwise Err with the last encountered error.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::retry_with_backoff;
+/// use std::time::Duration;
+///
+/// let mut calls = 0;
+/// let result: Result<(), usize> = retry_with_backoff(
+///     || {
+///         calls += 1;
+///         Err(calls)
+///     },
+///     3,
+///     Duration::ZERO,
+///     2.0,
+///     None,
+///     0.5,
+/// );
+/// assert_eq!(result, Err(3));
+/// assert_eq!(calls, 3);
+///
+/// let mut calls = 0;
+/// let result = retry_with_backoff(
+///     || {
+///         calls += 1;
+///         if calls < 2 { Err(calls) } else { Ok("done") }
+///     },
+///     5,
+///     Duration::from_micros(1),
+///     f64::NAN,
+///     Some(Duration::from_millis(1)),
+///     1.0,
+/// );
+/// assert_eq!(result, Ok("done"));
+/// assert_eq!(calls, 2);
+/// ```
+pub fn retry_with_backoff<F, T, E>(
+    mut action: F,
+    max_attempts: usize,
+    base: Duration,
+    multiplier: f64,
+    max_delay: Option<Duration>,
+    jitter: f64,
+) -> Result<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+    E: std::fmt::Debug,
+{
+    let max_attempts = max_attempts.max(1);
+    let mut backoff = Backoff::new(base, multiplier, max_delay);
+    let mut last_error = None;
+
+    for attempt in 1..=max_attempts {
+        match action() {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                eprintln!("Attempt {} failed: {:?}", attempt, err);
+                if attempt < max_attempts {
+                    if let Some(delay) = backoff.next() {
+                        sleep(apply_jitter(delay, jitter, max_delay));
+                    }
+                }
+                last_error = Some(err);
             }
         }
     }
-    Ok(()) // This line should never be reached
+
+    Err(last_error.expect("at least one attempt is always made"))
 }
 
 /// Throttle the execution of a function to occur at most once within a specified duration.
@@ -196,8 +1076,83 @@ where
     }
 }
 
+/// Limits operations to at most `max_operations` within any sliding window of `window`.
+///
+/// Each successful acquisition is remembered for `window`; once it falls out of the window
+/// its slot becomes available again, so bursts are allowed up to the limit.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::RateLimiter;
+/// use std::thread::sleep;
+/// use std::time::Duration;
+///
+/// let mut limiter = RateLimiter::new(2, Duration::from_millis(200));
+/// assert!(limiter.try_acquire());
+/// assert!(limiter.try_acquire());
+/// assert!(!limiter.try_acquire());
+///
+/// // Wait well past the window so both slots have certainly been released.
+/// sleep(Duration::from_millis(500));
+/// assert!(limiter.try_acquire());
+/// assert!(limiter.try_acquire());
+/// assert!(!limiter.try_acquire());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    max_operations: usize,
+    window: Duration,
+    acquired: VecDeque<Instant>,
+}
+
+impl RateLimiter {
+    /// Create a new rate limiter.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_operations` - The number of operations allowed within a window.
+    /// * `window` - The length of the sliding window.
+    ///
+    /// # Returns
+    ///
+    /// A new `RateLimiter` with every slot available.
+    pub fn new(max_operations: usize, window: Duration) -> Self {
+        RateLimiter {
+            max_operations,
+            window,
+            acquired: VecDeque::with_capacity(max_operations),
+        }
+    }
+
+    /// Try to perform an operation now.
+    ///
+    /// # Returns
+    ///
+    /// True if the operation is allowed and has been counted, false if the limit is reached.
+    pub fn try_acquire(&mut self) -> bool {
+        let now = Instant::now();
+        while let Some(&oldest) = self.acquired.front() {
+            if now.duration_since(oldest) < self.window {
+                break;
+            }
+            self.acquired.pop_front();
+        }
+
+        if self.acquired.len() < self.max_operations {
+            self.acquired.push_back(now);
+            true
+        } else {
+            false
+        }
+    }
+}
+
 /// Execute a function with a specified timeout duration.
 ///
+/// The action runs once on a separate thread. If it does not finish in time it is left
+/// running in the background and its completion is ignored.
+///
 /// # Arguments
 ///
 /// * `action` - The action to be executed.
@@ -205,20 +1160,94 @@ where
 ///
 /// # Returns
 ///
-/// Ok(()) if the action completes within the specified timeout, otherwise Err indicating timeout.
+/// Ok(()) if the action completes within the specified timeout, otherwise Err indicating a timeout or a panic.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::with_timeout;
+/// use std::time::Duration;
+///
+/// assert_eq!(with_timeout(|| {}, Duration::from_millis(200)), Ok(()));
+/// assert_eq!(
+///     with_timeout(|| std::thread::sleep(Duration::from_millis(200)), Duration::from_millis(20)),
+///     Err("Timeout reached")
+/// );
+/// ```
 pub fn with_timeout<F>(action: F, timeout: Duration) -> Result<(), &'static str>
 where
-    F: Fn(),
+    F: FnOnce() + Send + 'static,
 {
-    let start_time = Instant::now();
-    while start_time.elapsed() < timeout {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
         action();
+        let _ = sender.send(());
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(()) => Ok(()),
+        Err(mpsc::RecvTimeoutError::Timeout) => Err("Timeout reached"),
+        Err(mpsc::RecvTimeoutError::Disconnected) => Err("Action panicked"),
+    }
+}
+
+/// Error returned when an async action does not complete within its timeout.
+#[cfg(feature = "async")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeoutError;
+
+#[cfg(feature = "async")]
+impl std::fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Timeout reached")
     }
-    Err("Timeout reached")
+}
+
+#[cfg(feature = "async")]
+impl std::error::Error for TimeoutError {}
+
+/// Await a future with a specified timeout duration.
+///
+/// Requires the `async` feature and must be run inside a tokio runtime. The future is
+/// dropped if it does not complete in time.
+///
+/// # Arguments
+///
+/// * `fut` - The future to be awaited.
+/// * `timeout` - The maximum duration for the future to complete.
+///
+/// # Returns
+///
+/// Ok with the future's output if it completes within the specified timeout, otherwise Err(TimeoutError).
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::{with_timeout_async, TimeoutError};
+/// use std::time::Duration;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// assert_eq!(with_timeout_async(async { 42 }, Duration::from_millis(100)).await, Ok(42));
+///
+/// let slow = tokio::time::sleep(Duration::from_millis(200));
+/// assert_eq!(with_timeout_async(slow, Duration::from_millis(10)).await, Err(TimeoutError));
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub async fn with_timeout_async<F>(fut: F, timeout: Duration) -> Result<F::Output, TimeoutError>
+where
+    F: std::future::Future,
+{
+    tokio::time::timeout(timeout, fut)
+        .await
+        .map_err(|_| TimeoutError)
 }
 
 /// Calculate the nth Fibonacci number with memoization.
 ///
+/// The memo is grown as needed, so it does not have to be pre-sized by the caller.
+///
 /// # Arguments
 ///
 /// * `n` - The index of the Fibonacci number to calculate.
@@ -227,7 +1256,20 @@ where
 /// # Returns
 ///
 /// The calculated Fibonacci number.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::memoized_fibonacci;
+///
+/// let mut memo = vec![None; 2];
+/// assert_eq!(memoized_fibonacci(10, &mut memo), 55);
+/// assert!(memo.len() > 10);
+/// ```
 pub fn memoized_fibonacci(n: u64, memo: &mut Vec<Option<u64>>) -> u64 {
+    if memo.len() <= n as usize {
+        memo.resize(n as usize + 1, None);
+    }
     if let Some(result) = memo[n as usize] {
         return result;
     }
@@ -239,6 +1281,37 @@ pub fn memoized_fibonacci(n: u64, memo: &mut Vec<Option<u64>>) -> u64 {
     result
 }
 
+/// Calculate the nth Fibonacci number without requiring a caller-provided memo.
+///
+/// # Arguments
+///
+/// * `n` - The index of the Fibonacci number to calculate.
+///
+/// # Returns
+///
+/// Ok with the calculated Fibonacci number, otherwise Err if it does not fit in a u64.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::fibonacci;
+///
+/// assert_eq!(fibonacci(0), Ok(0));
+/// assert_eq!(fibonacci(10), Ok(55));
+/// assert_eq!(fibonacci(90), Ok(2_880_067_194_370_816_120));
+/// assert_eq!(fibonacci(93), Ok(12_200_160_415_121_876_738));
+/// assert!(fibonacci(94).is_err());
+/// ```
+pub fn fibonacci(n: u64) -> Result<u64, &'static str> {
+    let (mut current, mut next) = (0u64, Some(1u64));
+    for _ in 0..n {
+        let value = next.ok_or("Fibonacci number overflows u64")?;
+        next = current.checked_add(value);
+        current = value;
+    }
+    Ok(current)
+}
+
 fn multiply(x: i32, y: i32) -> i32 {
     x * y
 }
@@ -256,3 +1329,49 @@ fn multiply(x: i32, y: i32) -> i32 {
 pub fn partial_multiply(y: i32) -> impl Fn(i32) -> i32 {
     move |x| multiply(x, y)
 }
+
+/// Compose two functions so that data flows from left to right.
+///
+/// # Arguments
+///
+/// * `f` - The function applied first.
+/// * `g` - The function applied to the output of `f`.
+///
+/// # Returns
+///
+/// A function computing `g(f(x))`.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::pipe;
+///
+/// let add_then_double = pipe(|x: i32| x + 1, |x: i32| x * 2);
+/// assert_eq!(add_then_double(5), 12);
+/// ```
+pub fn pipe<A, B, C>(f: impl Fn(A) -> B, g: impl Fn(B) -> C) -> impl Fn(A) -> C {
+    move |x| g(f(x))
+}
+
+/// Compose two functions so that data flows from right to left.
+///
+/// # Arguments
+///
+/// * `f` - The function applied to the output of `g`.
+/// * `g` - The function applied first.
+///
+/// # Returns
+///
+/// A function computing `f(g(x))`.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::functions::compose;
+///
+/// let double_then_add = compose(|x: i32| x + 1, |x: i32| x * 2);
+/// assert_eq!(double_then_add(5), 11);
+/// ```
+pub fn compose<A, B, C>(f: impl Fn(B) -> C, g: impl Fn(A) -> B) -> impl Fn(A) -> C {
+    move |x| f(g(x))
+}
@@ -15,6 +15,35 @@ where
     vec.into_iter().for_each(f);
 }
 
+/// Folds a Vec from `init`, returning the accumulator after each element.
+///
+/// The initial value itself is not included, so the result has one entry per element.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::running_fold;
+///
+/// assert_eq!(running_fold(vec![1, 2, 3, 4], 0, |sum, x| sum + x), vec![1, 3, 6, 10]);
+/// assert_eq!(
+///     running_fold(vec!["a", "b"], String::new(), |acc, s| format!("{}{}", acc, s)),
+///     vec!["a", "ab"]
+/// );
+/// ```
+pub fn running_fold<T, A, F>(vec: Vec<T>, init: A, mut f: F) -> Vec<A>
+where
+    A: Clone,
+    F: FnMut(&A, T) -> A,
+{
+    let mut acc = init;
+    let mut steps = Vec::with_capacity(vec.len());
+    for item in vec {
+        acc = f(&acc, item);
+        steps.push(acc.clone());
+    }
+    steps
+}
+
 /// Converts a Vec of key-value pairs into a HashMap.
 pub fn vec_to_hashmap<T, U>(vec: Vec<(T, U)>) -> HashMap<T, U>
 where
@@ -23,33 +52,311 @@ where
     vec.into_iter().collect()
 }
 
+/// Groups the elements of a Vec into buckets keyed by the result of `key_fn`.
+///
+/// Elements are appended to their group's Vec in the order they appear in the input.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::group_by;
+///
+/// let groups = group_by(vec!["apple", "bean", "avocado", "beet", "cherry"], |word| {
+///     word.chars().next().unwrap()
+/// });
+/// assert_eq!(groups[&'a'], vec!["apple", "avocado"]);
+/// assert_eq!(groups[&'b'], vec!["bean", "beet"]);
+/// assert_eq!(groups[&'c'], vec!["cherry"]);
+/// assert_eq!(groups.len(), 3);
+/// ```
+pub fn group_by<T, K, F>(vec: Vec<T>, key_fn: F) -> HashMap<K, Vec<T>>
+where
+    K: Eq + Hash,
+    F: Fn(&T) -> K,
+{
+    let mut groups: HashMap<K, Vec<T>> = HashMap::new();
+    for item in vec {
+        groups.entry(key_fn(&item)).or_default().push(item);
+    }
+    groups
+}
+
+/// Splits a Vec into contiguous chunks of at most `size` elements, moving the elements.
+///
+/// The final chunk may be shorter. Panics if `size` is 0.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::chunk;
+///
+/// assert_eq!(chunk((1..=7).collect(), 3), vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+/// assert_eq!(chunk(Vec::<i32>::new(), 3), Vec::<Vec<i32>>::new());
+/// ```
+pub fn chunk<T>(vec: Vec<T>, size: usize) -> Vec<Vec<T>> {
+    assert!(size > 0, "chunk size must be non-zero");
+    let mut chunks = Vec::with_capacity(vec.len().div_ceil(size));
+    let mut items = vec.into_iter().peekable();
+    while items.peek().is_some() {
+        chunks.push(items.by_ref().take(size).collect());
+    }
+    chunks
+}
+
+/// Returns owned, overlapping windows of `size` consecutive elements.
+///
+/// Returns an empty Vec if `size` is 0 or larger than the input.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::windows_owned;
+///
+/// assert_eq!(windows_owned(&[1, 2, 3], 2), vec![vec![1, 2], vec![2, 3]]);
+/// assert!(windows_owned(&[1, 2, 3], 4).is_empty());
+/// assert!(windows_owned(&[1, 2, 3], 0).is_empty());
+/// ```
+pub fn windows_owned<T: Clone>(vec: &[T], size: usize) -> Vec<Vec<T>> {
+    if size == 0 {
+        return Vec::new();
+    }
+    vec.windows(size).map(<[T]>::to_vec).collect()
+}
+
 /// Extends a Vec with a specified number of default values.
 pub fn extend_with_defaults<T: Default>(vec: &mut Vec<T>, count: usize) {
     vec.extend(std::iter::repeat_with(T::default).take(count));
 }
 
 /// Removes duplicate elements from a Vec.
+///
+/// The order of the result is unspecified; use `deduplicate_stable` to keep input order.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::deduplicate;
+///
+/// let mut unique = deduplicate(vec![3, 1, 3, 2, 1]);
+/// unique.sort();
+/// assert_eq!(unique, vec![1, 2, 3]);
+/// ```
 pub fn deduplicate<T: Eq + Hash + Clone>(vec: Vec<T>) -> Vec<T> {
     let set: HashSet<_> = vec.into_iter().collect();
     set.into_iter().collect()
 }
 
+/// Removes duplicate elements from a Vec, keeping the first occurrence of each in input order.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::deduplicate_stable;
+///
+/// assert_eq!(deduplicate_stable(vec![3, 1, 3, 2, 1]), vec![3, 1, 2]);
+/// ```
+pub fn deduplicate_stable<T: Eq + Hash + Clone>(vec: Vec<T>) -> Vec<T> {
+    let mut seen = HashSet::new();
+    let mut unique = Vec::new();
+    for item in vec {
+        if seen.insert(item.clone()) {
+            unique.push(item);
+        }
+    }
+    unique
+}
+
+/// Removes elements whose key has already been seen, keeping the first occurrence of each key.
+///
+/// Unlike `deduplicate`, the remaining elements stay in their input order.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::dedup_by_key;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct User {
+///     id: u32,
+///     name: &'static str,
+/// }
+///
+/// let users = vec![
+///     User { id: 2, name: "bea" },
+///     User { id: 1, name: "ada" },
+///     User { id: 2, name: "bob" },
+///     User { id: 3, name: "cy" },
+///     User { id: 1, name: "al" },
+/// ];
+/// let names: Vec<_> = dedup_by_key(users, |user| user.id)
+///     .into_iter()
+///     .map(|user| user.name)
+///     .collect();
+/// assert_eq!(names, vec!["bea", "ada", "cy"]);
+/// ```
+pub fn dedup_by_key<T, K, F>(vec: Vec<T>, key_fn: F) -> Vec<T>
+where
+    K: Eq + Hash,
+    F: Fn(&T) -> K,
+{
+    let mut seen = HashSet::new();
+    vec.into_iter()
+        .filter(|item| seen.insert(key_fn(item)))
+        .collect()
+}
+
+/// Counts how many times each element occurs in a Vec.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::frequency_count;
+///
+/// let counts = frequency_count(vec!["a", "b", "a"]);
+/// assert_eq!(counts.len(), 2);
+/// assert_eq!(counts["a"], 2);
+/// assert_eq!(counts["b"], 1);
+/// ```
+pub fn frequency_count<T: Eq + Hash>(vec: Vec<T>) -> HashMap<T, usize> {
+    let mut counts = HashMap::new();
+    for item in vec {
+        *counts.entry(item).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Returns the `n` most frequent elements with their counts, most frequent first.
+///
+/// Elements with equal counts are ordered by their first appearance in the input.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::most_common;
+///
+/// let words = vec!["b", "a", "c", "a", "b", "a", "d", "c"];
+/// assert_eq!(most_common(words.clone(), 2), vec![("a", 3), ("b", 2)]);
+/// assert_eq!(most_common(words, 3), vec![("a", 3), ("b", 2), ("c", 2)]);
+/// ```
+pub fn most_common<T: Eq + Hash>(vec: Vec<T>, n: usize) -> Vec<(T, usize)> {
+    let mut counts: HashMap<T, (usize, usize)> = HashMap::new();
+    for (index, item) in vec.into_iter().enumerate() {
+        counts.entry(item).or_insert((0, index)).0 += 1;
+    }
+
+    let mut ranked: Vec<(T, (usize, usize))> = counts.into_iter().collect();
+    ranked.sort_by(|(_, (count_a, first_a)), (_, (count_b, first_b))| {
+        count_b.cmp(count_a).then(first_a.cmp(first_b))
+    });
+    ranked
+        .into_iter()
+        .take(n)
+        .map(|(item, (count, _))| (item, count))
+        .collect()
+}
+
+/// Sorts a Vec in ascending order, keeping equal elements in their input order.
+pub fn sort_asc<T: Ord>(vec: &mut [T]) {
+    vec.sort();
+}
+
 /// Sorts a Vec in descending order.
-pub fn sort_desc<T: Ord>(vec: &mut Vec<T>) {
+pub fn sort_desc<T: Ord>(vec: &mut [T]) {
     vec.sort_by(|a, b| b.cmp(a));
 }
 
+/// Sorts a Vec in descending order of the key returned by `key_fn`.
+///
+/// The sort is stable, so elements with equal keys keep their input order.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::sort_by_key_desc;
+///
+/// let mut scores = vec![("ada", 3), ("bea", 5), ("cy", 3), ("dee", 9)];
+/// sort_by_key_desc(&mut scores, |&(_, score)| score);
+/// assert_eq!(scores, vec![("dee", 9), ("bea", 5), ("ada", 3), ("cy", 3)]);
+/// ```
+pub fn sort_by_key_desc<T, K, F>(vec: &mut [T], key_fn: F)
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    vec.sort_by_key(|item| std::cmp::Reverse(key_fn(item)));
+}
+
 /// Finds the index of the first element in a Vec that satisfies a predicate.
 pub fn find_index<T, F>(vec: Vec<T>, predicate: F) -> Option<usize>
 where
     F: Fn(&T) -> bool,
 {
-    vec.iter().position(|x| predicate(x))
+    vec.iter().position(predicate)
+}
+
+/// Finds the index of the first element whose key equals `target` using a binary search.
+///
+/// The slice must be sorted in ascending order by `key_fn`; otherwise the result is unspecified.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::binary_search_index;
+///
+/// struct Entry {
+///     id: u32,
+///     label: &'static str,
+/// }
+///
+/// let entries = [
+///     Entry { id: 1, label: "one" },
+///     Entry { id: 3, label: "three" },
+///     Entry { id: 3, label: "trois" },
+///     Entry { id: 7, label: "seven" },
+/// ];
+/// assert_eq!(binary_search_index(&entries, &1, |e| e.id), Some(0));
+/// assert_eq!(binary_search_index(&entries, &3, |e| e.id), Some(1));
+/// assert_eq!(entries[1].label, "three");
+/// assert_eq!(binary_search_index(&entries, &7, |e| e.id), Some(3));
+/// assert_eq!(binary_search_index(&entries, &4, |e| e.id), None);
+/// assert_eq!(binary_search_index(&entries, &9, |e| e.id), None);
+/// ```
+pub fn binary_search_index<T, K, F>(slice: &[T], target: &K, key_fn: F) -> Option<usize>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    let index = slice.partition_point(|item| key_fn(item) < *target);
+    slice
+        .get(index)
+        .filter(|item| key_fn(item) == *target)
+        .map(|_| index)
 }
 
 /// Zips two Vecs into a Vec of pairs.
 pub fn zip_vecs<T, U>(vec1: Vec<T>, vec2: Vec<U>) -> Vec<(T, U)> {
-    vec1.into_iter().zip(vec2.into_iter()).collect()
+    vec1.into_iter().zip(vec2).collect()
+}
+
+/// Pairs every element of `a` with every element of `b`.
+///
+/// Pairs are ordered by `a` first, then by `b`.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::cartesian_product;
+///
+/// assert_eq!(
+///     cartesian_product(&[1, 2], &['a', 'b']),
+///     vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]
+/// );
+/// assert!(cartesian_product::<i32, char>(&[1, 2], &[]).is_empty());
+/// ```
+pub fn cartesian_product<T: Clone, U: Clone>(a: &[T], b: &[U]) -> Vec<(T, U)> {
+    a.iter()
+        .flat_map(|x| b.iter().map(move |y| (x.clone(), y.clone())))
+        .collect()
 }
 
 /// Applies a function to the value inside an Option, if it exists.
@@ -78,6 +385,63 @@ where
     }
 }
 
+/// Calculates the average of values convertible to f64, summing in f64 to avoid overflow.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::average_f64;
+///
+/// assert_eq!(average_f64(&[1, 2, 3, 4]), Some(2.5));
+/// assert_eq!(average_f64(&[u32::MAX, u32::MAX]), Some(u32::MAX as f64));
+/// assert_eq!(average_f64::<f32>(&[]), None);
+/// ```
+pub fn average_f64<T: Into<f64> + Copy>(values: &[T]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let sum: f64 = values.iter().map(|&x| x.into()).sum();
+    Some(sum / values.len() as f64)
+}
+
+/// Finds the median of a slice, returning the lower of the two middle values for even lengths.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::median;
+///
+/// assert_eq!(median(&[5, 1, 3]), Some(3));
+/// assert_eq!(median(&[4, 1, 3, 2]), Some(2));
+/// assert_eq!(median::<i32>(&[]), None);
+/// ```
+pub fn median<T: Ord + Clone>(values: &[T]) -> Option<T> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted: Vec<&T> = values.iter().collect();
+    sorted.sort();
+    Some(sorted[(sorted.len() - 1) / 2].clone())
+}
+
+/// Finds the most frequent value in a slice, preferring the earliest one on ties.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::mode;
+///
+/// assert_eq!(mode(&[1, 2, 2, 3, 2, 1]), Some(2));
+/// assert_eq!(mode(&["x", "y"]), Some("x"));
+/// assert_eq!(mode::<i32>(&[]), None);
+/// ```
+pub fn mode<T: Eq + Hash + Clone>(values: &[T]) -> Option<T> {
+    most_common(values.iter().collect(), 1)
+        .into_iter()
+        .next()
+        .map(|(value, _)| value.clone())
+}
+
 /// Merges two Vecs into a single Vec.
 pub fn merge_vecs<T>(vec1: Vec<T>, vec2: Vec<T>) -> Vec<T>
 where
@@ -86,6 +450,93 @@ where
     [vec1, vec2].concat()
 }
 
+/// Alternates the elements of two Vecs, starting with `a`.
+///
+/// Once the shorter Vec runs out, the rest of the longer one is appended in order.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::interleave;
+///
+/// assert_eq!(interleave(vec![1, 3, 5], vec![2, 4, 6]), vec![1, 2, 3, 4, 5, 6]);
+/// assert_eq!(interleave(vec![1, 3], vec![2, 4, 6, 8]), vec![1, 2, 3, 4, 6, 8]);
+/// assert_eq!(interleave(vec![1, 3, 5, 7], vec![2]), vec![1, 2, 3, 5, 7]);
+/// ```
+pub fn interleave<T>(a: Vec<T>, b: Vec<T>) -> Vec<T> {
+    let mut result = Vec::with_capacity(a.len() + b.len());
+    let (mut a, mut b) = (a.into_iter(), b.into_iter());
+    loop {
+        match (a.next(), b.next()) {
+            (Some(x), Some(y)) => {
+                result.push(x);
+                result.push(y);
+            }
+            (Some(x), None) => {
+                result.push(x);
+                result.extend(a);
+                break;
+            }
+            (None, Some(y)) => {
+                result.push(y);
+                result.extend(b);
+                break;
+            }
+            (None, None) => break,
+        }
+    }
+    result
+}
+
+/// Concatenates nested Vecs into a single Vec, moving the elements in order.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::flatten_vec;
+///
+/// assert_eq!(flatten_vec(vec![vec![1, 2], vec![3], vec![], vec![4, 5]]), vec![1, 2, 3, 4, 5]);
+/// ```
+pub fn flatten_vec<T>(nested: Vec<Vec<T>>) -> Vec<T> {
+    nested.into_iter().flatten().collect()
+}
+
+/// Error returned when nested Vecs that must be rectangular have different lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaggedError;
+
+impl std::fmt::Display for RaggedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "all inner Vecs must have the same length")
+    }
+}
+
+impl std::error::Error for RaggedError {}
+
+/// Turns rows into columns, failing if the rows have different lengths.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::{transpose_vec, RaggedError};
+///
+/// let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+/// assert_eq!(transpose_vec(grid), Ok(vec![vec![1, 4], vec![2, 5], vec![3, 6]]));
+/// assert_eq!(transpose_vec(vec![vec![1, 2], vec![3]]), Err(RaggedError));
+/// assert_eq!(transpose_vec(Vec::<Vec<i32>>::new()), Ok(vec![]));
+/// ```
+pub fn transpose_vec<T>(rows: Vec<Vec<T>>) -> Result<Vec<Vec<T>>, RaggedError> {
+    let columns = rows.first().map_or(0, Vec::len);
+    if rows.iter().any(|row| row.len() != columns) {
+        return Err(RaggedError);
+    }
+
+    let mut rows: Vec<_> = rows.into_iter().map(Vec::into_iter).collect();
+    Ok((0..columns)
+        .map(|_| rows.iter_mut().filter_map(Iterator::next).collect())
+        .collect())
+}
+
 /// Filters elements in a Vec based on a provided predicate function.
 pub fn filter_by<T, F>(vec: Vec<T>, predicate: F) -> Vec<T>
 where
@@ -94,12 +545,112 @@ where
     vec.into_iter().filter(|x| predicate(x)).collect()
 }
 
-/// Clones each element in the Vec and doubles the cloned values.
-pub fn clone_and_double<T: Clone + std::ops::Mul<Output = T>>(vec: &Vec<T>) -> Vec<T>
+/// Returns the leading run of elements that satisfy the predicate, consuming the Vec.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::take_while_owned;
+///
+/// assert_eq!(take_while_owned(vec![2, 4, 5, 6], |x| x % 2 == 0), vec![2, 4]);
+/// assert!(take_while_owned(vec![1, 2], |x| x % 2 == 0).is_empty());
+/// ```
+pub fn take_while_owned<T, F>(vec: Vec<T>, pred: F) -> Vec<T>
+where
+    F: Fn(&T) -> bool,
+{
+    vec.into_iter().take_while(|x| pred(x)).collect()
+}
+
+/// Returns the elements after the leading run that satisfies the predicate, consuming the Vec.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::drop_while_owned;
+///
+/// assert_eq!(drop_while_owned(vec![2, 4, 5, 6], |x| x % 2 == 0), vec![5, 6]);
+/// assert!(drop_while_owned(vec![2, 4], |x| x % 2 == 0).is_empty());
+/// ```
+pub fn drop_while_owned<T, F>(vec: Vec<T>, pred: F) -> Vec<T>
+where
+    F: Fn(&T) -> bool,
+{
+    vec.into_iter().skip_while(|x| pred(x)).collect()
+}
+
+/// Splits a Vec at the first element that satisfies the predicate.
+///
+/// The matching element starts the second half. If no element matches, everything ends up
+/// in the first half and the second half is empty.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::split_when;
+///
+/// assert_eq!(split_when(vec![1, 2, 3, 4], |&x| x > 2), (vec![1, 2], vec![3, 4]));
+/// assert_eq!(split_when(vec![1, 2], |&x| x > 2), (vec![1, 2], vec![]));
+/// assert_eq!(split_when(vec![5, 1], |&x| x > 2), (vec![], vec![5, 1]));
+/// ```
+pub fn split_when<T, F>(mut vec: Vec<T>, pred: F) -> (Vec<T>, Vec<T>)
+where
+    F: Fn(&T) -> bool,
+{
+    let index = vec.iter().position(pred).unwrap_or(vec.len());
+    let tail = vec.split_off(index);
+    (vec, tail)
+}
+
+/// Filters and transforms a Vec in place, dropping elements for which `f` returns None
+/// and replacing the rest with the returned value.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::retain_map;
+///
+/// let mut numbers = vec![1, 2, 3, 4, 5, 6];
+/// retain_map(&mut numbers, |&x| if x % 2 == 0 { Some(x * 2) } else { None });
+/// assert_eq!(numbers, vec![4, 8, 12]);
+/// ```
+pub fn retain_map<T, F>(vec: &mut Vec<T>, mut f: F)
 where
-    T: Copy,
+    F: FnMut(&T) -> Option<T>,
 {
-    vec.iter().cloned().map(|x| x * x).collect()
+    vec.retain_mut(|item| match f(item) {
+        Some(value) => {
+            *item = value;
+            true
+        }
+        None => false,
+    });
+}
+
+/// Clones each element in the slice and doubles the cloned values.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::clone_and_double;
+///
+/// assert_eq!(clone_and_double(&vec![1, 2, 3]), vec![2, 4, 6]);
+/// ```
+pub fn clone_and_double<T: Clone + std::ops::Add<Output = T>>(vec: &[T]) -> Vec<T> {
+    vec.iter().map(|x| x.clone() + x.clone()).collect()
+}
+
+/// Clones each element in the slice and squares the cloned values.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::square_each;
+///
+/// assert_eq!(square_each(&vec![1, 2, 3]), vec![1, 4, 9]);
+/// ```
+pub fn square_each<T: Clone + std::ops::Mul<Output = T>>(vec: &[T]) -> Vec<T> {
+    vec.iter().map(|x| x.clone() * x.clone()).collect()
 }
 
 /// Finds the maximum value in a slice and returns it as an Option.
@@ -107,7 +658,166 @@ pub fn find_max<T: Ord>(slice: &[T]) -> Option<&T> {
     slice.iter().max()
 }
 
+/// Finds the element with the largest key, preferring the earliest one on ties.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::max_by_key;
+///
+/// let words = ["kiwi", "banana", "cherry", "fig"];
+/// assert_eq!(max_by_key(&words, |word| word.len()), Some(&"banana"));
+/// assert_eq!(max_by_key(&[] as &[&str], |word| word.len()), None);
+/// ```
+pub fn max_by_key<T, K, F>(slice: &[T], f: F) -> Option<&T>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    slice
+        .iter()
+        .map(|item| (f(item), item))
+        .reduce(|best, next| if next.0 > best.0 { next } else { best })
+        .map(|(_, item)| item)
+}
+
+/// Finds the element with the smallest key, preferring the earliest one on ties.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::min_by_key;
+///
+/// let words = ["kiwi", "banana", "fig", "pea"];
+/// assert_eq!(min_by_key(&words, |word| word.len()), Some(&"fig"));
+/// assert_eq!(min_by_key(&[] as &[&str], |word| word.len()), None);
+/// ```
+pub fn min_by_key<T, K, F>(slice: &[T], f: F) -> Option<&T>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    slice
+        .iter()
+        .map(|item| (f(item), item))
+        .reduce(|best, next| if next.0 < best.0 { next } else { best })
+        .map(|(_, item)| item)
+}
+
 /// Swaps elements at the specified indices in a mutable Vec.
-pub fn swap_elements<T>(vec: &mut Vec<T>, index1: usize, index2: usize) {
+pub fn swap_elements<T>(vec: &mut [T], index1: usize, index2: usize) {
     vec.swap(index1, index2);
 }
+
+/// Rotates a Vec in place so the first `n` elements move to the end.
+///
+/// `n` is taken modulo the length, so any value is accepted, including on an empty Vec.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::rotate_left;
+///
+/// let mut values = vec![1, 2, 3, 4, 5];
+/// rotate_left(&mut values, 2);
+/// assert_eq!(values, vec![3, 4, 5, 1, 2]);
+///
+/// rotate_left(&mut values, 12);
+/// assert_eq!(values, vec![5, 1, 2, 3, 4]);
+/// ```
+pub fn rotate_left<T>(vec: &mut [T], n: usize) {
+    if !vec.is_empty() {
+        let len = vec.len();
+        vec.rotate_left(n % len);
+    }
+}
+
+/// Rotates a Vec in place so the last `n` elements move to the front.
+///
+/// `n` is taken modulo the length, so any value is accepted, including on an empty Vec.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::rotate_right;
+///
+/// let mut values = vec![1, 2, 3, 4, 5];
+/// rotate_right(&mut values, 2);
+/// assert_eq!(values, vec![4, 5, 1, 2, 3]);
+///
+/// let mut empty: Vec<i32> = Vec::new();
+/// rotate_right(&mut empty, 3);
+/// assert!(empty.is_empty());
+/// ```
+pub fn rotate_right<T>(vec: &mut [T], n: usize) {
+    if !vec.is_empty() {
+        let len = vec.len();
+        vec.rotate_right(n % len);
+    }
+}
+
+/// Returns every ordering of the items, in lexicographic order of their indices.
+///
+/// The result holds `n!` Vecs for `n` items, so it grows very quickly; keep inputs to
+/// around 8 items or fewer. An empty slice yields a single empty ordering.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::permutations;
+///
+/// let orderings = permutations(&[1, 2, 3]);
+/// assert_eq!(orderings.len(), 6);
+/// assert_eq!(orderings[0], vec![1, 2, 3]);
+/// assert_eq!(orderings[1], vec![1, 3, 2]);
+/// assert_eq!(orderings[5], vec![3, 2, 1]);
+/// assert_eq!(permutations::<i32>(&[]), vec![Vec::<i32>::new()]);
+/// ```
+pub fn permutations<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    if items.is_empty() {
+        return vec![Vec::new()];
+    }
+
+    let mut result = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        let mut rest = items.to_vec();
+        rest.remove(i);
+        for mut tail in permutations(&rest) {
+            tail.insert(0, item.clone());
+            result.push(tail);
+        }
+    }
+    result
+}
+
+/// Returns every way to choose `k` of the items, keeping each choice in index order.
+///
+/// Returns an empty Vec if `k` is larger than the number of items, and a single empty
+/// combination if `k` is 0.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::generics::combinations;
+///
+/// assert_eq!(combinations(&[1, 2, 3], 2), vec![vec![1, 2], vec![1, 3], vec![2, 3]]);
+/// assert_eq!(combinations(&[1, 2, 3], 0), vec![Vec::<i32>::new()]);
+/// assert!(combinations(&[1, 2, 3], 4).is_empty());
+/// ```
+pub fn combinations<T: Clone>(items: &[T], k: usize) -> Vec<Vec<T>> {
+    if k == 0 {
+        return vec![Vec::new()];
+    }
+    if k > items.len() {
+        return Vec::new();
+    }
+
+    let mut result = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        for mut tail in combinations(&items[i + 1..], k - 1) {
+            tail.insert(0, item.clone());
+            result.push(tail);
+        }
+    }
+    result
+}
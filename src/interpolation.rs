@@ -0,0 +1,106 @@
+/// Linearly interpolate between two values.
+///
+/// # Arguments
+///
+/// * `a` - The value returned when `t` is 0.
+/// * `b` - The value returned when `t` is 1.
+/// * `t` - The interpolation factor, which may fall outside `[0, 1]` to extrapolate.
+///
+/// # Returns
+///
+/// The value `t` of the way from `a` to `b`.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::interpolation::lerp;
+///
+/// assert_eq!(lerp(10.0, 20.0, 0.0), 10.0);
+/// assert_eq!(lerp(10.0, 20.0, 0.5), 15.0);
+/// assert_eq!(lerp(10.0, 20.0, 1.0), 20.0);
+/// assert_eq!(lerp(10.0, 20.0, 2.0), 30.0);
+/// ```
+pub fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// Linearly interpolate between two values with `t` clamped to `[0, 1]`.
+///
+/// # Arguments
+///
+/// * `a` - The value returned when `t` is 0 or less.
+/// * `b` - The value returned when `t` is 1 or more.
+/// * `t` - The interpolation factor.
+///
+/// # Returns
+///
+/// The value `t` of the way from `a` to `b`, never leaving the range between them.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::interpolation::lerp_clamped;
+///
+/// assert_eq!(lerp_clamped(10.0, 20.0, 0.5), 15.0);
+/// assert_eq!(lerp_clamped(10.0, 20.0, 2.0), 20.0);
+/// assert_eq!(lerp_clamped(10.0, 20.0, -1.0), 10.0);
+/// ```
+pub fn lerp_clamped(a: f64, b: f64, t: f64) -> f64 {
+    lerp(a, b, t.clamp(0.0, 1.0))
+}
+
+/// Find how far a value lies between two others, the inverse of `lerp`.
+///
+/// # Arguments
+///
+/// * `a` - The value mapped to 0.
+/// * `b` - The value mapped to 1.
+/// * `value` - The value to locate.
+///
+/// # Returns
+///
+/// The factor `t` such that `lerp(a, b, t) == value`, or 0.0 if `a` and `b` are equal.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::interpolation::inverse_lerp;
+///
+/// assert_eq!(inverse_lerp(10.0, 20.0, 15.0), 0.5);
+/// assert_eq!(inverse_lerp(10.0, 20.0, 10.0), 0.0);
+/// assert_eq!(inverse_lerp(10.0, 20.0, 20.0), 1.0);
+/// assert_eq!(inverse_lerp(5.0, 5.0, 5.0), 0.0);
+/// ```
+pub fn inverse_lerp(a: f64, b: f64, value: f64) -> f64 {
+    if a == b {
+        return 0.0;
+    }
+    (value - a) / (b - a)
+}
+
+/// Map a value from one range onto another.
+///
+/// # Arguments
+///
+/// * `value` - The value to map.
+/// * `in_min` - The start of the input range.
+/// * `in_max` - The end of the input range.
+/// * `out_min` - The start of the output range.
+/// * `out_max` - The end of the output range.
+///
+/// # Returns
+///
+/// The value at the same relative position in the output range.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::interpolation::remap;
+///
+/// assert_eq!(remap(50.0, 0.0, 100.0, 0.0, 1.0), 0.5);
+/// assert_eq!(remap(25.0, 0.0, 100.0, 0.0, 1.0), 0.25);
+/// assert_eq!(remap(0.0, -1.0, 1.0, 0.0, 255.0), 127.5);
+/// ```
+pub fn remap(value: f64, in_min: f64, in_max: f64, out_min: f64, out_max: f64) -> f64 {
+    lerp(out_min, out_max, inverse_lerp(in_min, in_max, value))
+}
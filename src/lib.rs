@@ -8,3 +8,5 @@ pub mod structs_and_enums; // Structs and Enums
 pub mod type_aliases;
 #[cfg(feature = "generics")]
 pub mod generics;
+#[cfg(feature = "interpolation")]
+pub mod interpolation;
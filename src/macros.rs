@@ -3,6 +3,9 @@
 /// # Example
 ///
 /// ```rust
+/// use qol_rs::printl;
+///
+/// let variable = 42;
 /// printl!("This is a debug message: {}", variable);
 /// ```
 #[macro_export]
@@ -17,7 +20,13 @@ macro_rules! printl {
 /// # Example
 ///
 /// ```rust
-/// error!("Failed to perform operation");
+/// use qol_rs::error;
+///
+/// fn operation() -> Result<(), Box<dyn std::error::Error>> {
+///     error!("Failed to perform operation");
+/// }
+///
+/// assert_eq!(operation().unwrap_err().to_string(), "Failed to perform operation");
 /// ```
 #[macro_export]
 macro_rules! error {
@@ -31,21 +40,75 @@ macro_rules! error {
 /// # Example
 ///
 /// ```rust
+/// use qol_rs::vec_of;
+///
 /// let my_vec = vec_of![1, 2, 3];
+/// assert_eq!(my_vec, vec![1, 2, 3]);
 /// ```
 #[macro_export]
 macro_rules! vec_of {
     ($($x:expr),*) => (vec![$($x),*]);
 }
 
+/// Create a HashMap with given key-value pairs.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::hashmap;
+///
+/// let my_map = hashmap! {
+///     "a" => 1,
+///     "b" => 2,
+/// };
+/// assert_eq!(my_map.len(), 2);
+/// assert_eq!(my_map["a"], 1);
+/// assert_eq!(my_map["b"], 2);
+/// ```
+#[macro_export]
+macro_rules! hashmap {
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut map = std::collections::HashMap::new();
+        $(map.insert($key, $value);)*
+        map
+    }};
+}
+
+/// Create a HashSet with given elements, collapsing duplicates.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::hashset;
+///
+/// let my_set = hashset! {1, 2, 2, 3, 1,};
+/// assert_eq!(my_set.len(), 3);
+/// assert!(my_set.contains(&2));
+/// assert!(!my_set.contains(&4));
+/// ```
+#[macro_export]
+macro_rules! hashset {
+    ($($x:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut set = std::collections::HashSet::new();
+        $(set.insert($x);)*
+        set
+    }};
+}
+
 /// Measure the time taken by a code block to execute.
 ///
 /// # Example
 ///
 /// ```rust
+/// use qol_rs::measure_time;
+///
 /// let (result, elapsed_time) = measure_time!({
-///     // code to be measured
+///     (1..=10).sum::<i32>()
 /// });
+/// assert_eq!(result, 55);
+/// println!("took {:?}", elapsed_time);
 /// ```
 #[macro_export]
 macro_rules! measure_time {
@@ -57,12 +120,206 @@ macro_rules! measure_time {
     }};
 }
 
+/// Time a code block, print how long it took, and return its result.
+///
+/// Without a label, the file name and line number are printed instead.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::time_it;
+///
+/// let sum = time_it!("summing", {
+///     (1..=10).sum::<i32>()
+/// });
+/// assert_eq!(sum, 55);
+///
+/// let text = time_it!({ String::from("unchanged") });
+/// assert_eq!(text, "unchanged");
+/// ```
+#[macro_export]
+macro_rules! time_it {
+    ($label:expr, $code:expr) => {{
+        let (result, elapsed) = $crate::measure_time!($code);
+        println!("[{}] took {:?}", $label, elapsed);
+        result
+    }};
+    ($code:expr) => {
+        $crate::time_it!(format_args!("{}:{}", file!(), line!()), $code)
+    };
+}
+
+/// Evaluate a fallible expression up to a number of times, with an optional delay between attempts.
+///
+/// Evaluates to the first `Ok`, or the last `Err` once every attempt has failed.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::retry;
+/// use std::time::Duration;
+///
+/// let mut calls = 0;
+/// let mut flaky = || {
+///     calls += 1;
+///     if calls < 2 { Err("not yet") } else { Ok(calls) }
+/// };
+/// assert_eq!(retry!(3, flaky()), Ok(2));
+///
+/// let failing: Result<(), &str> = retry!(2, Err("never"), Duration::from_millis(1));
+/// assert_eq!(failing, Err("never"));
+/// ```
+#[macro_export]
+macro_rules! retry {
+    ($attempts:expr, $expr:expr) => {
+        $crate::retry!($attempts, $expr, std::time::Duration::ZERO)
+    };
+    ($attempts:expr, $expr:expr, $delay:expr) => {{
+        let attempts: usize = $attempts;
+        let mut attempt = 1;
+        loop {
+            match $expr {
+                Ok(value) => break Ok(value),
+                Err(err) if attempt >= attempts => break Err(err),
+                Err(_) => {
+                    attempt += 1;
+                    std::thread::sleep($delay);
+                }
+            }
+        }
+    }};
+}
+
+/// Evaluate a block only the first time this call site runs, returning a `&'static` reference
+/// to the cached value on every call.
+///
+/// The value's type must be given explicitly, since it is stored in a static `OnceLock`.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::once;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// static RUNS: AtomicUsize = AtomicUsize::new(0);
+///
+/// fn expensive() -> &'static Vec<u64> {
+///     once!(Vec<u64>, {
+///         RUNS.fetch_add(1, Ordering::SeqCst);
+///         (1..=5).map(|x| x * x).collect()
+///     })
+/// }
+///
+/// assert_eq!(expensive(), &vec![1, 4, 9, 16, 25]);
+/// assert_eq!(expensive(), &vec![1, 4, 9, 16, 25]);
+/// assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+/// ```
+#[macro_export]
+macro_rules! once {
+    ($type:ty, $init:block) => {{
+        static CELL: std::sync::OnceLock<$type> = std::sync::OnceLock::new();
+        CELL.get_or_init(|| $init)
+    }};
+}
+
+/// Assert that two floating-point values differ by no more than an epsilon.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::assert_approx_eq;
+///
+/// assert_approx_eq!(0.1 + 0.2, 0.3, 1e-9);
+/// ```
+///
+/// A failure reports both values, the epsilon, and their difference. NaN is never
+/// approximately equal to anything:
+///
+/// ```rust
+/// use qol_rs::assert_approx_eq;
+/// use std::panic;
+///
+/// let message = |result: std::thread::Result<()>| {
+///     *result.unwrap_err().downcast::<String>().unwrap()
+/// };
+///
+/// let far = message(panic::catch_unwind(|| assert_approx_eq!(1.0, 1.5, 1e-9)));
+/// assert!(far.starts_with("assertion failed: `(left ≈ right)`"));
+/// assert!(far.contains("eps: `1e-9`"));
+/// assert!(far.contains("difference: `0.5`"));
+///
+/// let nan = message(panic::catch_unwind(|| assert_approx_eq!(f64::NAN, 1.0, 1e-9)));
+/// assert!(nan.contains("left: `NaN`"));
+/// assert!(nan.contains("eps: `1e-9`"));
+/// assert!(nan.contains("difference: `NaN`"));
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($a:expr, $b:expr, $eps:expr $(,)?) => {{
+        let (a, b, eps) = ($a, $b, $eps);
+        let difference = if a > b { a - b } else { b - a };
+        #[allow(clippy::neg_cmp_op_on_partial_ord)]
+        if !(difference <= eps) {
+            panic!(
+                "assertion failed: `(left ≈ right)`\n  left: `{:?}`\n right: `{:?}`\n   eps: `{:?}`\n  difference: `{:?}`",
+                a, b, eps, difference
+            );
+        }
+    }};
+}
+
+/// Like `assert_approx_eq!`, but only checked when debug assertions are enabled.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::debug_assert_approx_eq;
+///
+/// debug_assert_approx_eq!(1.0_f64.sqrt(), 1.0, 1e-12);
+/// ```
+///
+/// ```rust
+/// use qol_rs::debug_assert_approx_eq;
+///
+/// let result = std::panic::catch_unwind(|| debug_assert_approx_eq!(f64::NAN, f64::NAN, 1e-9));
+/// if cfg!(debug_assertions) {
+///     let message = result.unwrap_err().downcast::<String>().unwrap();
+///     assert!(message.contains("difference: `NaN`"));
+/// }
+/// ```
+#[macro_export]
+macro_rules! debug_assert_approx_eq {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_approx_eq!($($arg)*);
+        }
+    };
+}
+
 /// Unwrap an Option or return early with an error message.
 ///
+/// `unwrap_or_return!(option, msg)` returns `()` when the Option is None, so it is meant for
+/// functions without a return value. `unwrap_or_return!(option, msg, value)` returns `value`
+/// instead, for functions that return something.
+///
 /// # Example
 ///
 /// ```rust
-/// let result = some_option.unwrap_or_return("Option is None");
+/// use qol_rs::unwrap_or_return;
+///
+/// fn first_char(s: &str) {
+///     let c = unwrap_or_return!(s.chars().next(), "Option is None");
+///     println!("{}", c);
+/// }
+///
+/// fn first_digit(s: &str) -> i32 {
+///     let c = unwrap_or_return!(s.chars().next(), "Option is None", -1);
+///     c.to_digit(10).map_or(-1, |d| d as i32)
+/// }
+///
+/// first_char("");
+/// assert_eq!(first_digit("7up"), 7);
+/// assert_eq!(first_digit(""), -1);
 /// ```
 #[macro_export]
 macro_rules! unwrap_or_return {
@@ -75,27 +332,82 @@ macro_rules! unwrap_or_return {
             }
         }
     };
+    ($result:expr, $msg:expr, $default:expr) => {
+        match $result {
+            Some(value) => value,
+            None => {
+                eprintln!("{}", $msg);
+                return $default;
+            }
+        }
+    };
 }
 
 /// Match an enum variant and execute corresponding code.
 ///
+/// The enum type is passed first, followed by the value to match. The type may be given as a
+/// path, such as `module::Enum`.
+///
 /// # Example
 ///
 /// ```rust
-/// match_enum!(my_enum,
-///     Variant1 => {
-///         // code for Variant1
-///     },
-///     Variant2 => {
-///         // code for Variant2
+/// use qol_rs::match_enum;
+///
+/// mod traffic {
+///     pub enum Light {
+///         Red,
+///         Amber,
+///         Green,
 ///     }
-/// );
+/// }
+///
+/// let action = |light: traffic::Light| {
+///     match_enum!(traffic::Light, light,
+///         Red => {
+///             "stop"
+///         },
+///         Amber => {
+///             "slow down"
+///         },
+///         Green => {
+///             "go"
+///         }
+///     )
+/// };
+/// assert_eq!(action(traffic::Light::Red), "stop");
+/// assert_eq!(action(traffic::Light::Amber), "slow down");
+/// assert_eq!(action(traffic::Light::Green), "go");
+///
+/// use traffic::Light;
+/// assert_eq!(match_enum!(Light, Light::Green, Red => { 0 }, Amber => { 1 }, Green => { 2 }), 2);
+///
+/// # #[cfg(feature = "sae")]
+/// # {
+/// use qol_rs::structs_and_enums::State;
+///
+/// let describe = |state: State| {
+///     match_enum!(qol_rs::structs_and_enums::State, state,
+///         Initial => {
+///             "not started"
+///         },
+///         InProgress => {
+///             "running"
+///         },
+///         Completed => {
+///             "done"
+///         }
+///     )
+/// };
+/// assert_eq!(describe(State::Initial), "not started");
+/// assert_eq!(describe(State::InProgress), "running");
+/// assert_eq!(describe(State::Completed), "done");
+/// # }
 /// ```
 #[macro_export]
 macro_rules! match_enum {
-    ($enum:expr, $($variant:ident => $code:block),*) => {
-        match $enum {
-            $(Enum::$variant => $code),*
+    ($enum_type:path, $value:expr, $($variant:ident => $code:block),* $(,)?) => {
+        match $value {
+            $(<$enum_type>::$variant => $code),*
         }
     };
 }
@@ -105,8 +417,11 @@ macro_rules! match_enum {
 /// # Example
 ///
 /// ```rust
+/// use qol_rs::lock;
+///
+/// let my_mutex = std::sync::Mutex::new(0);
 /// lock!(my_mutex, {
-///     // code inside the locked section
+///     println!("only one thread runs this at a time");
 /// });
 /// ```
 #[macro_export]
@@ -119,10 +434,31 @@ macro_rules! lock {
 
 /// Unwrap a Result or return early with a custom error message.
 ///
+/// `custom_result!(result, msg)` prints the error and returns `()`, so it is meant for
+/// functions without a return value. `custom_result!(result, msg, Err)` instead prints the
+/// message and returns the original error, converted with `.into()`, for functions returning
+/// a `Result` such as `type_aliases::Result`.
+///
 /// # Example
 ///
 /// ```rust
-/// let result = some_result.unwrap_or_return("Failed to get result");
+/// use qol_rs::custom_result;
+///
+/// type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+///
+/// fn parse(s: &str) {
+///     let number = custom_result!(s.parse::<i32>(), "Failed to get result");
+///     println!("{}", number);
+/// }
+///
+/// fn parse_and_double(s: &str) -> Result<i32> {
+///     let number = custom_result!(s.parse::<i32>(), "Failed to get result", Err);
+///     Ok(number * 2)
+/// }
+///
+/// parse("not a number");
+/// assert_eq!(parse_and_double("21").unwrap(), 42);
+/// assert!(parse_and_double("x").unwrap_err().is::<std::num::ParseIntError>());
 /// ```
 #[macro_export]
 macro_rules! custom_result {
@@ -135,6 +471,15 @@ macro_rules! custom_result {
             }
         }
     };
+    ($result:expr, $msg:expr, Err) => {
+        match $result {
+            Ok(value) => value,
+            Err(err) => {
+                eprintln!("Error: {}", $msg);
+                return Err(err.into());
+            }
+        }
+    };
 }
 
 /// Repeat a code block a specified number of times.
@@ -142,9 +487,13 @@ macro_rules! custom_result {
 /// # Example
 ///
 /// ```rust
+/// use qol_rs::repeat_n;
+///
+/// let mut count = 0;
 /// repeat_n!(5, {
-///     // code to be repeated 5 times
+///     count += 1;
 /// });
+/// assert_eq!(count, 5);
 /// ```
 #[macro_export]
 macro_rules! repeat_n {
@@ -158,7 +507,13 @@ macro_rules! repeat_n {
 /// # Example
 ///
 /// ```rust
-/// log_and_return!("Error: Something went wrong");
+/// use qol_rs::log_and_return;
+///
+/// fn run() {
+///     log_and_return!("Error: Something went wrong");
+/// }
+///
+/// run();
 /// ```
 #[macro_export]
 macro_rules! log_and_return {
@@ -168,16 +523,72 @@ macro_rules! log_and_return {
     }};
 }
 
-/// Concatenate multiple strings into an HTML-formatted string.
+/// Select between two values based on a condition, evaluating only the chosen branch.
 ///
 /// # Example
 ///
 /// ```rust
-/// let html_string = html!("<p>", "This is", "an example", "</p>");
+/// use qol_rs::ternary;
+///
+/// let mut side_effects = 0;
+/// let sign = ternary!(-3 < 0, "negative", {
+///     side_effects += 1;
+///     "non-negative"
+/// });
+/// assert_eq!(sign, "negative");
+/// assert_eq!(side_effects, 0);
+/// ```
+#[macro_export]
+macro_rules! ternary {
+    ($cond:expr, $a:expr, $b:expr $(,)?) => {
+        if $cond {
+            $a
+        } else {
+            $b
+        }
+    };
+}
+
+/// Concatenate multiple values into a single HTML string.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::html;
+///
+/// let html_string = html!("<p>", "This is ", "an example", "</p>");
+/// assert_eq!(html_string, "<p>This is an example</p>");
+/// assert_eq!(html!("a", "b", "c"), "abc");
 /// ```
 #[macro_export]
 macro_rules! html {
-    ($($content:expr),*) => {
-        format!($("<html>{}</html>")+, $($content),*);
+    ($($content:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut html = String::new();
+        $(html.push_str(&$content.to_string());)*
+        html
+    }};
+}
+
+/// Thread a value through a sequence of functions, applying them from left to right.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::pipeline;
+///
+/// assert_eq!(pipeline!(5, |x| x + 1, |x| x * 2), 12);
+/// assert_eq!(pipeline!(5, |x: i32| x.to_string()), "5");
+/// assert_eq!(pipeline!(5), 5);
+/// ```
+#[macro_export]
+macro_rules! pipeline {
+    ($value:expr $(,)?) => {
+        $value
     };
+    ($value:expr, $($f:expr),+ $(,)?) => {{
+        let value = $value;
+        $(let value = ($f)(value);)+
+        value
+    }};
 }
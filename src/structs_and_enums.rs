@@ -1,84 +1,3006 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+use std::time::{Duration, Instant};
+
+/// Tolerance used when comparing floating-point values.
+const EPSILON: f64 = 1e-9;
+
+/// Writes a float, honouring the formatter's precision if one was given.
+fn write_float(f: &mut fmt::Formatter, value: f64) -> fmt::Result {
+    match f.precision() {
+        Some(precision) => write!(f, "{:.*}", precision, value),
+        None => write!(f, "{}", value),
+    }
+}
+
 /// Represents a point in a two-dimensional space with x and y coordinates.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::Point2D;
+///
+/// let a = Point2D::new(1.0, 2.0);
+/// let b = Point2D::from((3.0, 4.0));
+/// assert_eq!(a + b, Point2D::new(4.0, 6.0));
+/// assert_eq!(b - a, Point2D::new(2.0, 2.0));
+/// assert_eq!(-a * 2.0, Point2D::new(-2.0, -4.0));
+/// assert_eq!(<(f64, f64)>::from(b), (3.0, 4.0));
+/// assert_eq!(Point2D::new(1.5, -2.0).to_string(), "(1.5, -2)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point2D {
     x: f64,
     y: f64,
 }
 
+impl Point2D {
+    /// Creates a new point from its x and y coordinates.
+    pub fn new(x: f64, y: f64) -> Self {
+        Point2D { x, y }
+    }
+
+    /// Returns the x coordinate.
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    /// Returns the y coordinate.
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// Returns the Euclidean distance between this point and another.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Point2D;
+    ///
+    /// let origin = Point2D::new(0.0, 0.0);
+    /// assert_eq!(origin.distance_to(&Point2D::new(3.0, 4.0)), 5.0);
+    /// ```
+    pub fn distance_to(&self, other: &Point2D) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+
+    /// Returns the point halfway between this point and another.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Point2D;
+    ///
+    /// let midpoint = Point2D::new(0.0, 0.0).midpoint(&Point2D::new(3.0, 4.0));
+    /// assert_eq!((midpoint.x(), midpoint.y()), (1.5, 2.0));
+    /// ```
+    pub fn midpoint(&self, other: &Point2D) -> Point2D {
+        Point2D::new((self.x + other.x) / 2.0, (self.y + other.y) / 2.0)
+    }
+
+    /// Moves the point by the given offsets.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Point2D;
+    ///
+    /// let mut point = Point2D::new(3.0, 4.0);
+    /// point.translate(1.0, -1.0);
+    /// assert_eq!(point, Point2D::new(4.0, 3.0));
+    /// ```
+    pub fn translate(&mut self, dx: f64, dy: f64) {
+        self.x += dx;
+        self.y += dy;
+    }
+
+    /// Returns the dot product of this point and another, treated as vectors.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Point2D;
+    ///
+    /// assert_eq!(Point2D::new(1.0, 2.0).dot(&Point2D::new(3.0, 4.0)), 11.0);
+    /// ```
+    pub fn dot(&self, other: &Point2D) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns the length of the point treated as a vector from the origin.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Point2D;
+    ///
+    /// assert_eq!(Point2D::new(3.0, 4.0).magnitude(), 5.0);
+    /// ```
+    pub fn magnitude(&self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    /// Returns the point rotated counterclockwise around the origin by the given angle in radians.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Point2D;
+    ///
+    /// let rotated = Point2D::new(1.0, 0.0).rotate(std::f64::consts::FRAC_PI_2);
+    /// assert!(rotated.x().abs() < 1e-10 && (rotated.y() - 1.0).abs() < 1e-10);
+    /// ```
+    pub fn rotate(&self, radians: f64) -> Point2D {
+        let (sin, cos) = radians.sin_cos();
+        Point2D::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
+    /// Returns the point rotated counterclockwise around `center` by the given angle in radians.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Point2D;
+    ///
+    /// let center = Point2D::new(1.0, 1.0);
+    /// let rotated = Point2D::new(2.0, 1.0).rotate_around(&center, std::f64::consts::PI);
+    /// assert!(rotated.x().abs() < 1e-10 && (rotated.y() - 1.0).abs() < 1e-10);
+    /// ```
+    pub fn rotate_around(&self, center: &Point2D, radians: f64) -> Point2D {
+        (*self - *center).rotate(radians) + *center
+    }
+}
+
+impl fmt::Display for Point2D {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(")?;
+        write_float(f, self.x)?;
+        write!(f, ", ")?;
+        write_float(f, self.y)?;
+        write!(f, ")")
+    }
+}
+
+impl From<(f64, f64)> for Point2D {
+    fn from((x, y): (f64, f64)) -> Self {
+        Point2D::new(x, y)
+    }
+}
+
+impl From<Point2D> for (f64, f64) {
+    fn from(point: Point2D) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl Add for Point2D {
+    type Output = Point2D;
+
+    fn add(self, other: Point2D) -> Point2D {
+        Point2D::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point2D {
+    type Output = Point2D;
+
+    fn sub(self, other: Point2D) -> Point2D {
+        Point2D::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for Point2D {
+    type Output = Point2D;
+
+    fn neg(self) -> Point2D {
+        Point2D::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<f64> for Point2D {
+    type Output = Point2D;
+
+    fn mul(self, scalar: f64) -> Point2D {
+        Point2D::new(self.x * scalar, self.y * scalar)
+    }
+}
+
 /// Represents a rectangle with width and height dimensions.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::Rectangle;
+///
+/// let rect = Rectangle::new(3.0, 4.0);
+/// assert_eq!(rect.area(), 12.0);
+/// assert_eq!(rect.perimeter(), 14.0);
+/// assert_eq!(rect.aspect_ratio(), 0.75);
+/// assert!(!rect.is_square());
+/// assert!(Rectangle::new(0.1 + 0.2, 0.3).is_square());
+///
+/// assert_eq!(rect.to_string(), "3×4");
+/// assert_eq!(format!("{:.1}", Rectangle::new(2.5, 1.26)), "2.5×1.3");
+///
+/// let mut scaled = rect;
+/// scaled.scale(2.0);
+/// assert_eq!(scaled, Rectangle::new(6.0, 8.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rectangle {
     width: f64,
     height: f64,
 }
 
+impl Rectangle {
+    /// Creates a new rectangle from its width and height.
+    pub fn new(width: f64, height: f64) -> Self {
+        Rectangle { width, height }
+    }
+
+    /// Returns the width of the rectangle.
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    /// Returns the height of the rectangle.
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Returns the area of the rectangle.
+    pub fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    /// Returns the perimeter of the rectangle.
+    pub fn perimeter(&self) -> f64 {
+        2.0 * (self.width + self.height)
+    }
+
+    /// Returns the ratio of the width to the height.
+    pub fn aspect_ratio(&self) -> f64 {
+        self.width / self.height
+    }
+
+    /// Returns true if the width and height are equal within a small tolerance.
+    pub fn is_square(&self) -> bool {
+        (self.width - self.height).abs() < EPSILON
+    }
+
+    /// Multiplies both dimensions by the given factor.
+    pub fn scale(&mut self, factor: f64) {
+        self.width *= factor;
+        self.height *= factor;
+    }
+}
+
+impl fmt::Display for Rectangle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_float(f, self.width)?;
+        write!(f, "×")?;
+        write_float(f, self.height)
+    }
+}
+
+/// Represents a rectangle placed in space, with its minimum corner at `origin`.
+///
+/// Edges are inclusive: points on the boundary are contained, and rectangles that only
+/// share an edge or corner intersect with a zero-area intersection.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::{Point2D, PositionedRect};
+///
+/// let a = PositionedRect::new(Point2D::new(0.0, 0.0), 4.0, 4.0);
+/// let b = PositionedRect::new(Point2D::new(2.0, 2.0), 4.0, 4.0);
+/// let far = PositionedRect::new(Point2D::new(10.0, 10.0), 1.0, 1.0);
+/// let touching = PositionedRect::new(Point2D::new(4.0, 0.0), 2.0, 2.0);
+///
+/// assert!(a.contains(&Point2D::new(1.0, 1.0)));
+/// assert!(a.contains(&Point2D::new(4.0, 4.0)));
+/// assert!(!a.contains(&Point2D::new(4.1, 1.0)));
+///
+/// assert!(a.intersects(&b));
+/// assert_eq!(a.intersection(&b), Some(PositionedRect::new(Point2D::new(2.0, 2.0), 2.0, 2.0)));
+///
+/// assert!(!a.intersects(&far));
+/// assert_eq!(a.intersection(&far), None);
+///
+/// assert!(a.intersects(&touching));
+/// assert_eq!(a.intersection(&touching), Some(PositionedRect::new(Point2D::new(4.0, 0.0), 0.0, 2.0)));
+///
+/// assert_eq!(b.center(), Point2D::new(4.0, 4.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PositionedRect {
+    origin: Point2D,
+    width: f64,
+    height: f64,
+}
+
+impl PositionedRect {
+    /// Creates a new positioned rectangle from its minimum corner and dimensions.
+    pub fn new(origin: Point2D, width: f64, height: f64) -> Self {
+        PositionedRect {
+            origin,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the minimum corner of the rectangle.
+    pub fn origin(&self) -> Point2D {
+        self.origin
+    }
+
+    /// Returns the width of the rectangle.
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    /// Returns the height of the rectangle.
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Returns the point at the middle of the rectangle.
+    pub fn center(&self) -> Point2D {
+        self.origin.midpoint(&self.max_corner())
+    }
+
+    /// Returns the corner opposite the origin.
+    fn max_corner(&self) -> Point2D {
+        Point2D::new(self.origin.x + self.width, self.origin.y + self.height)
+    }
+
+    /// Returns true if the point lies inside the rectangle or on its boundary.
+    pub fn contains(&self, p: &Point2D) -> bool {
+        let max = self.max_corner();
+        p.x >= self.origin.x && p.x <= max.x && p.y >= self.origin.y && p.y <= max.y
+    }
+
+    /// Returns true if the rectangles overlap or touch.
+    pub fn intersects(&self, other: &PositionedRect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the overlapping region of two rectangles, if any.
+    pub fn intersection(&self, other: &PositionedRect) -> Option<PositionedRect> {
+        let (max, other_max) = (self.max_corner(), other.max_corner());
+        let min_x = self.origin.x.max(other.origin.x);
+        let min_y = self.origin.y.max(other.origin.y);
+        let max_x = max.x.min(other_max.x);
+        let max_y = max.y.min(other_max.y);
+
+        if min_x <= max_x && min_y <= max_y {
+            Some(PositionedRect::new(
+                Point2D::new(min_x, min_y),
+                max_x - min_x,
+                max_y - min_y,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the smallest axis-aligned rectangle enclosing all the points, or `None` if there are none.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::{bounding_box, Point2D, PositionedRect};
+///
+/// let points = [
+///     Point2D::new(1.0, 5.0),
+///     Point2D::new(-2.0, 3.0),
+///     Point2D::new(4.0, -1.0),
+/// ];
+/// assert_eq!(
+///     bounding_box(&points),
+///     Some(PositionedRect::new(Point2D::new(-2.0, -1.0), 6.0, 6.0))
+/// );
+/// assert_eq!(
+///     bounding_box(&[Point2D::new(2.0, 3.0)]),
+///     Some(PositionedRect::new(Point2D::new(2.0, 3.0), 0.0, 0.0))
+/// );
+/// assert_eq!(bounding_box(&[]), None);
+/// ```
+pub fn bounding_box(points: &[Point2D]) -> Option<PositionedRect> {
+    let (first, rest) = points.split_first()?;
+    let (min, max) = rest.iter().fold((*first, *first), |(min, max), p| {
+        (
+            Point2D::new(min.x.min(p.x), min.y.min(p.y)),
+            Point2D::new(max.x.max(p.x), max.y.max(p.y)),
+        )
+    });
+    Some(PositionedRect::new(min, max.x - min.x, max.y - min.y))
+}
+
 /// Represents a color using red, green, and blue components.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::Color;
+///
+/// let orange = Color::new(255, 136, 0);
+/// assert_eq!((orange.red(), orange.green(), orange.blue()), (255, 136, 0));
+/// assert_eq!(orange.to_string(), "#ff8800");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color {
     red: u8,
     green: u8,
     blue: u8,
 }
 
+/// Represents the ways parsing a hex color string can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorParseError {
+    /// The string does not have 3 or 6 hex digits.
+    InvalidLength,
+    /// The string contains a character that is not a hex digit.
+    InvalidCharacter,
+}
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ColorParseError::InvalidLength => write!(f, "hex color must have 3 or 6 digits"),
+            ColorParseError::InvalidCharacter => {
+                write!(f, "hex color contains a non-hex character")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+impl Color {
+    /// Creates a new color from its red, green, and blue components.
+    pub fn new(red: u8, green: u8, blue: u8) -> Self {
+        Color { red, green, blue }
+    }
+
+    /// Returns the red component.
+    pub fn red(&self) -> u8 {
+        self.red
+    }
+
+    /// Returns the green component.
+    pub fn green(&self) -> u8 {
+        self.green
+    }
+
+    /// Returns the blue component.
+    pub fn blue(&self) -> u8 {
+        self.blue
+    }
+
+    /// Parses a color from `#RRGGBB`, `RRGGBB`, `#RGB`, or `RGB` hex notation.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::{Color, ColorParseError};
+    ///
+    /// assert_eq!(Color::from_hex("#ff8800"), Ok(Color::new(255, 136, 0)));
+    /// assert_eq!(Color::from_hex("f80"), Ok(Color::new(255, 136, 0)));
+    /// assert_eq!(Color::from_hex("#ff88"), Err(ColorParseError::InvalidLength));
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Color, ColorParseError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ColorParseError::InvalidCharacter);
+        }
+
+        let channel = |digits: &str| u8::from_str_radix(digits, 16).unwrap();
+        match digits.len() {
+            6 => Ok(Color::new(
+                channel(&digits[0..2]),
+                channel(&digits[2..4]),
+                channel(&digits[4..6]),
+            )),
+            3 => Ok(Color::new(
+                channel(&digits[0..1]) * 0x11,
+                channel(&digits[1..2]) * 0x11,
+                channel(&digits[2..3]) * 0x11,
+            )),
+            _ => Err(ColorParseError::InvalidLength),
+        }
+    }
+
+    /// Formats the color as a lowercase `#rrggbb` hex string.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Color;
+    ///
+    /// assert_eq!(Color::new(255, 136, 0).to_hex(), "#ff8800");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+
+    /// Linearly interpolates each channel towards `other`, with `t` clamped to `[0, 1]`.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Color;
+    ///
+    /// let (black, white) = (Color::new(0, 0, 0), Color::new(255, 255, 255));
+    /// assert_eq!(black.lerp(&white, 0.5), Color::new(128, 128, 128));
+    /// ```
+    pub fn lerp(&self, other: &Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |from: u8, to: u8| {
+            let value = from as f64 + (to as f64 - from as f64) * t;
+            value.round().clamp(0.0, 255.0) as u8
+        };
+        Color::new(
+            channel(self.red, other.red),
+            channel(self.green, other.green),
+            channel(self.blue, other.blue),
+        )
+    }
+
+    /// Returns the relative luminance in `[0, 1]` using the Rec. 709 coefficients.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Color;
+    ///
+    /// assert!((Color::new(255, 255, 255).luminance() - 1.0).abs() < 1e-9);
+    /// assert_eq!(Color::new(0, 0, 0).luminance(), 0.0);
+    /// ```
+    pub fn luminance(&self) -> f64 {
+        (0.2126 * self.red as f64 + 0.7152 * self.green as f64 + 0.0722 * self.blue as f64) / 255.0
+    }
+
+    /// Returns the complementary color.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Color;
+    ///
+    /// assert_eq!(Color::new(255, 136, 0).invert(), Color::new(0, 119, 255));
+    /// ```
+    pub fn invert(&self) -> Color {
+        Color::new(255 - self.red, 255 - self.green, 255 - self.blue)
+    }
+
+    /// Converts the color to hue in degrees `[0, 360)` and saturation and lightness in `[0, 1]`.
+    ///
+    /// Achromatic colors have an undefined hue, which is reported as 0.0.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Color;
+    ///
+    /// assert_eq!(Color::new(255, 0, 0).to_hsl(), (0.0, 1.0, 0.5));
+    /// ```
+    pub fn to_hsl(&self) -> (f64, f64, f64) {
+        let r = self.red as f64 / 255.0;
+        let g = self.green as f64 / 255.0;
+        let b = self.blue as f64 / 255.0;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        let lightness = (max + min) / 2.0;
+
+        if delta == 0.0 {
+            return (0.0, 0.0, lightness);
+        }
+
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        (hue, saturation, lightness)
+    }
+
+    /// Creates a color from hue in degrees and saturation and lightness in `[0, 1]`.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Color;
+    ///
+    /// assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::new(255, 0, 0));
+    /// ```
+    pub fn from_hsl(h: f64, s: f64, l: f64) -> Color {
+        let h = h.rem_euclid(360.0);
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = chroma * (1.0 - ((h / 60.0).rem_euclid(2.0) - 1.0).abs());
+        let m = l - chroma / 2.0;
+        let (r, g, b) = match (h / 60.0) as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        let channel = |value: f64| ((value + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Color::new(channel(r), channel(g), channel(b))
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+/// Returns true if the year is a leap year in the Gregorian calendar.
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::is_leap_year;
+///
+/// assert!(is_leap_year(2024));
+/// assert!(!is_leap_year(1900));
+/// assert!(is_leap_year(2000));
+/// ```
+// `u16::is_multiple_of` needs Rust 1.87, which this crate does not require.
+#[allow(clippy::manual_is_multiple_of)]
+pub fn is_leap_year(year: u16) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns the number of days in the month of the given year, or 0 for an invalid month.
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
 /// Represents a date with day, month, and year components.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::Date;
+///
+/// let leap_day = Date::new(2024, 2, 29).unwrap();
+/// assert_eq!((leap_day.year(), leap_day.month(), leap_day.day()), (2024, 2, 29));
+/// assert!(leap_day < Date::new(2024, 3, 1).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
 pub struct Date {
     day: u8,
     month: u8,
     year: u16,
 }
 
-/// Represents a range of values from start to end.
+/// Represents the ways building a date can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateError {
+    /// The month is not in `1..=12`.
+    InvalidMonth,
+    /// The day does not exist in the given month.
+    InvalidDay,
+    /// The string is not formatted as `YYYY-MM-DD`.
+    InvalidFormat,
+}
+
+/// Represents a day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DateError::InvalidMonth => write!(f, "month must be between 1 and 12"),
+            DateError::InvalidDay => write!(f, "day does not exist in the given month"),
+            DateError::InvalidFormat => write!(f, "date must be formatted as YYYY-MM-DD"),
+        }
+    }
+}
+
+impl std::error::Error for DateError {}
+
+impl TryFrom<String> for Date {
+    type Error = DateError;
+
+    fn try_from(s: String) -> Result<Date, DateError> {
+        Date::parse_iso8601(&s)
+    }
+}
+
+impl From<Date> for String {
+    fn from(date: Date) -> String {
+        date.to_iso8601()
+    }
+}
+
+impl PartialOrd for Date {
+    fn partial_cmp(&self, other: &Date) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Date {
+    fn cmp(&self, other: &Date) -> Ordering {
+        (self.year, self.month, self.day).cmp(&(other.year, other.month, other.day))
+    }
+}
+
+impl Date {
+    /// Creates a new date, validating the month and the day against the month length.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::{Date, DateError};
+    ///
+    /// assert!(Date::new(2024, 2, 29).is_ok());
+    /// assert_eq!(Date::new(2023, 2, 29), Err(DateError::InvalidDay));
+    /// assert_eq!(Date::new(2024, 13, 1), Err(DateError::InvalidMonth));
+    /// ```
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Date, DateError> {
+        if !(1..=12).contains(&month) {
+            return Err(DateError::InvalidMonth);
+        }
+        if day == 0 || day > days_in_month(year, month) {
+            return Err(DateError::InvalidDay);
+        }
+        Ok(Date { day, month, year })
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting at 1.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the day of the week, computed with Zeller's congruence.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::{Date, Weekday};
+    ///
+    /// assert_eq!(Date::new(2024, 2, 29).unwrap().day_of_week(), Weekday::Thursday);
+    /// ```
+    pub fn day_of_week(&self) -> Weekday {
+        let (month, year) = if self.month < 3 {
+            (self.month as i64 + 12, self.year as i64 - 1)
+        } else {
+            (self.month as i64, self.year as i64)
+        };
+        let (century, year_of_century) = (year.div_euclid(100), year.rem_euclid(100));
+        let h = (self.day as i64
+            + 13 * (month + 1) / 5
+            + year_of_century
+            + year_of_century / 4
+            + century.div_euclid(4)
+            + 5 * century)
+            .rem_euclid(7);
+
+        match h {
+            0 => Weekday::Saturday,
+            1 => Weekday::Sunday,
+            2 => Weekday::Monday,
+            3 => Weekday::Tuesday,
+            4 => Weekday::Wednesday,
+            5 => Weekday::Thursday,
+            _ => Weekday::Friday,
+        }
+    }
+
+    /// Formats the date as a zero-padded ISO 8601 `YYYY-MM-DD` string.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Date;
+    ///
+    /// assert_eq!(Date::new(987, 3, 5).unwrap().to_iso8601(), "0987-03-05");
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+
+    /// Returns the number of days since 1970-01-01.
+    fn days_since_epoch(&self) -> i64 {
+        let year = self.year as i64 - if self.month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month = self.month as i64;
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Builds the date that is the given number of days after 1970-01-01.
+    fn from_days_since_epoch(days: i64) -> Date {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        Date {
+            day: day as u8,
+            month: month as u8,
+            year: u16::try_from(year).expect("date is outside the supported range of years"),
+        }
+    }
+
+    /// Returns the date `n` days after this one, or before it if `n` is negative.
+    ///
+    /// Panics if the result falls outside the years representable by a u16.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Date;
+    ///
+    /// let new_years_eve = Date::new(2023, 12, 31).unwrap();
+    /// assert_eq!(new_years_eve.add_days(1), Date::new(2024, 1, 1).unwrap());
+    /// assert_eq!(new_years_eve.add_days(-30), Date::new(2023, 12, 1).unwrap());
+    /// ```
+    pub fn add_days(&self, n: i64) -> Date {
+        Date::from_days_since_epoch(self.days_since_epoch() + n)
+    }
+
+    /// Returns the number of days from this date to `other`, negative if `other` is earlier.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Date;
+    ///
+    /// let new_years_eve = Date::new(2023, 12, 31).unwrap();
+    /// let leap_day = Date::new(2024, 2, 29).unwrap();
+    /// assert_eq!(new_years_eve.days_between(&leap_day), 60);
+    /// assert_eq!(leap_day.days_between(&new_years_eve), -60);
+    /// ```
+    pub fn days_between(&self, other: &Date) -> i64 {
+        other.days_since_epoch() - self.days_since_epoch()
+    }
+
+    /// Parses a date from an ISO 8601 `YYYY-MM-DD` string.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::{Date, DateError};
+    ///
+    /// assert_eq!(Date::parse_iso8601("2024-02-29"), Date::new(2024, 2, 29));
+    /// assert_eq!(Date::parse_iso8601("2024-2-29"), Err(DateError::InvalidFormat));
+    /// ```
+    pub fn parse_iso8601(s: &str) -> Result<Date, DateError> {
+        let bytes = s.as_bytes();
+        let well_formed = bytes.len() == 10
+            && bytes.iter().enumerate().all(|(i, b)| match i {
+                4 | 7 => *b == b'-',
+                _ => b.is_ascii_digit(),
+            });
+        if !well_formed {
+            return Err(DateError::InvalidFormat);
+        }
+
+        let year = s[0..4].parse().map_err(|_| DateError::InvalidFormat)?;
+        let month = s[5..7].parse().map_err(|_| DateError::InvalidFormat)?;
+        let day = s[8..10].parse().map_err(|_| DateError::InvalidFormat)?;
+        Date::new(year, month, day)
+    }
+}
+
+/// Represents a half-open range of values from start (inclusive) to end (exclusive).
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::Range;
+///
+/// let range = Range::new(1, 5);
+/// assert!(range.contains(&1));
+/// assert!(!range.contains(&5));
+/// assert!(!range.contains(&0));
+///
+/// assert!(range.overlaps(&Range::new(4, 8)));
+/// assert!(!range.overlaps(&Range::new(5, 8)));
+///
+/// assert_eq!(range.iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+/// assert_eq!(Range::new(-2i64, 1).into_iter().sum::<i64>(), -3);
+///
+/// assert_eq!(range.clamp(-3), 1);
+/// assert_eq!(range.clamp(3), 3);
+/// assert_eq!(range.clamp(9), 5);
+/// assert!(!range.contains(&range.clamp(9)));
+/// assert_eq!(Range::new(3, 3).clamp(7), 3);
+///
+/// assert_eq!(range.intersection(&Range::new(3, 10)), Some(Range::new(3, 5)));
+/// assert_eq!(range.intersection(&Range::new(5, 10)), None);
+/// assert_eq!(range.intersection(&Range::new(4, 2)), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Range<T> {
     start: T,
     end: T,
 }
 
+impl<T> Range<T> {
+    /// Creates a new range from its inclusive start and exclusive end.
+    pub fn new(start: T, end: T) -> Self {
+        Range { start, end }
+    }
+
+    /// Returns the inclusive start of the range.
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+
+    /// Returns the exclusive end of the range.
+    pub fn end(&self) -> &T {
+        &self.end
+    }
+}
+
+impl<T: PartialOrd> Range<T> {
+    /// Returns true if the range contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Returns true if the value lies within the range.
+    pub fn contains(&self, value: &T) -> bool {
+        *value >= self.start && *value < self.end
+    }
+
+    /// Returns true if the ranges share at least one value.
+    pub fn overlaps(&self, other: &Range<T>) -> bool {
+        !self.is_empty() && !other.is_empty() && self.start < other.end && other.start < self.end
+    }
+}
+
+impl<T: PartialOrd + Clone> Range<T> {
+    /// Pins a value to `[start, end]`, so unlike `contains` the result may be the excluded end.
+    ///
+    /// There is no general "value just below end", so values at or past the end are pinned
+    /// to end itself; check the result with `contains` if it must lie inside the range. Empty
+    /// and inverted ranges (start >= end) pin every value to start, likewise outside the range.
+    pub fn clamp(&self, value: T) -> T {
+        if value <= self.start || self.start > self.end {
+            self.start.clone()
+        } else if value >= self.end {
+            self.end.clone()
+        } else {
+            value
+        }
+    }
+
+    /// Returns the sub-range shared by both ranges, or None if they are disjoint.
+    ///
+    /// Empty and inverted ranges never intersect anything.
+    pub fn intersection(&self, other: &Range<T>) -> Option<Range<T>> {
+        if !self.overlaps(other) {
+            return None;
+        }
+
+        let start = if self.start >= other.start {
+            &self.start
+        } else {
+            &other.start
+        };
+        let end = if self.end <= other.end {
+            &self.end
+        } else {
+            &other.end
+        };
+        Some(Range::new(start.clone(), end.clone()))
+    }
+}
+
+impl<T: Clone> Range<T>
+where
+    std::ops::Range<T>: Iterator<Item = T>,
+{
+    /// Returns an iterator over the values in the range.
+    pub fn iter(&self) -> std::ops::Range<T> {
+        self.start.clone()..self.end.clone()
+    }
+}
+
+impl<T> IntoIterator for Range<T>
+where
+    std::ops::Range<T>: Iterator<Item = T>,
+{
+    type Item = T;
+    type IntoIter = std::ops::Range<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.start..self.end
+    }
+}
+
 /// Represents a key-value pair.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::KeyValuePair;
+///
+/// let pair = KeyValuePair::new("answer", 42);
+/// assert_eq!((*pair.key(), *pair.value()), ("answer", 42));
+/// assert_eq!(pair.to_string(), "answer=42");
+///
+/// let pair = pair.map_value(|value| value.to_string() + "!");
+/// assert_eq!(pair.value(), "42!");
+/// assert_eq!(KeyValuePair::from(("a", 1)).into_tuple(), ("a", 1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KeyValuePair<K, V> {
     key: K,
     value: V,
 }
 
-/// Represents a Uniform Resource Locator with protocol, host, and path components.
+impl<K, V> KeyValuePair<K, V> {
+    /// Creates a new pair from a key and a value.
+    pub fn new(key: K, value: V) -> Self {
+        KeyValuePair { key, value }
+    }
+
+    /// Returns a reference to the key.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns a reference to the value.
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+
+    /// Consumes the pair and returns its key and value as a tuple.
+    pub fn into_tuple(self) -> (K, V) {
+        (self.key, self.value)
+    }
+
+    /// Transforms the value while keeping the key.
+    pub fn map_value<W>(self, f: impl FnOnce(V) -> W) -> KeyValuePair<K, W> {
+        KeyValuePair::new(self.key, f(self.value))
+    }
+}
+
+impl<K, V> From<(K, V)> for KeyValuePair<K, V> {
+    fn from((key, value): (K, V)) -> Self {
+        KeyValuePair::new(key, value)
+    }
+}
+
+impl<K: fmt::Display, V: fmt::Display> fmt::Display for KeyValuePair<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}={}", self.key, self.value)
+    }
+}
+
+/// Represents a Uniform Resource Locator with protocol, host, path, and query components.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::{UrlParseError, URL};
+///
+/// let url = URL::parse("https://example.com/a/b").unwrap();
+/// assert_eq!((url.protocol(), url.host(), url.path()), ("https", "example.com", "/a/b"));
+/// assert_eq!(url.to_string(), "https://example.com/a/b");
+///
+/// assert_eq!(URL::parse("http://example.com").unwrap().path(), "/");
+/// assert_eq!(URL::parse("notaurl"), Err(UrlParseError::MissingScheme));
+/// assert_eq!(URL::parse("https:///a/b"), Err(UrlParseError::MissingHost));
+///
+/// let mut search = URL::parse("https://example.com/search").unwrap();
+/// search.with_query("q", "rust & cargo");
+/// search.with_query("page", "2");
+/// assert_eq!(search.get_query("q"), Some("rust & cargo"));
+/// assert_eq!(search.get_query("missing"), None);
+/// assert_eq!(search.to_string(), "https://example.com/search?q=rust%20%26%20cargo&page=2");
+/// assert_eq!(URL::parse(&search.to_string()), Ok(search.clone()));
+///
+/// // A `%` not followed by two hex digits is kept literally, even at the end of a value.
+/// let malformed = URL::parse("https://example.com/?a=%+1&b=100%&c=%zz").unwrap();
+/// assert_eq!(malformed.get_query("a"), Some("% 1"));
+/// assert_eq!(malformed.get_query("b"), Some("100%"));
+/// assert_eq!(malformed.get_query("c"), Some("%zz"));
+///
+/// # #[cfg(feature = "serde")]
+/// # {
+/// let json = serde_json::to_string(&search).unwrap();
+/// assert_eq!(serde_json::from_str::<URL>(&json).unwrap(), search);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
 pub struct URL {
     protocol: String,
     host: String,
     path: String,
+    query: Vec<(String, String)>,
+}
+
+/// Represents the ways parsing a URL can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UrlParseError {
+    /// The URL has no valid `scheme://` prefix.
+    MissingScheme,
+    /// The URL has nothing between the scheme and the path.
+    MissingHost,
+}
+
+impl fmt::Display for UrlParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UrlParseError::MissingScheme => write!(f, "URL is missing a scheme"),
+            UrlParseError::MissingHost => write!(f, "URL is missing a host"),
+        }
+    }
+}
+
+impl std::error::Error for UrlParseError {}
+
+impl URL {
+    /// Parses a URL of the form `scheme://host/path?key=value`, defaulting the path to `/`.
+    ///
+    /// Query keys and values are percent-decoded; malformed escapes are kept literally
+    /// rather than rejected.
+    pub fn parse(url: &str) -> Result<URL, UrlParseError> {
+        let (protocol, rest) = url.split_once("://").ok_or(UrlParseError::MissingScheme)?;
+        let valid_scheme = protocol.starts_with(|c: char| c.is_ascii_alphabetic())
+            && protocol
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+        if !valid_scheme {
+            return Err(UrlParseError::MissingScheme);
+        }
+
+        let (rest, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let (host, path) = match rest.find('/') {
+            Some(index) => rest.split_at(index),
+            None => (rest, "/"),
+        };
+        if host.is_empty() {
+            return Err(UrlParseError::MissingHost);
+        }
+
+        Ok(URL {
+            protocol: protocol.to_string(),
+            host: host.to_string(),
+            path: path.to_string(),
+            query: query
+                .split('&')
+                .filter(|pair| !pair.is_empty())
+                .map(|pair| {
+                    let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                    (percent_decode(key), percent_decode(value))
+                })
+                .collect(),
+        })
+    }
+
+    /// Returns the protocol, such as `https`.
+    pub fn protocol(&self) -> &str {
+        &self.protocol
+    }
+
+    /// Returns the host, including any port.
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Returns the path, which always starts with `/`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Appends a query parameter, keeping previously added parameters in order.
+    pub fn with_query(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.query.push((key.into(), value.into()));
+    }
+
+    /// Returns the value of the first query parameter with the given key.
+    pub fn get_query(&self, key: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Percent-encodes every byte outside the unreserved URL characters.
+fn percent_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Decodes `%XX` escapes and `+` as a space, keeping a `%` that starts no valid escape as is.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        // `from_str_radix` accepts a leading sign, so check for two hex digits explicitly.
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], escaped) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (b'+', _) => {
+                decoded.push(b' ');
+                i += 1;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+impl TryFrom<String> for URL {
+    type Error = UrlParseError;
+
+    fn try_from(s: String) -> Result<URL, UrlParseError> {
+        URL::parse(&s)
+    }
+}
+
+impl From<URL> for String {
+    fn from(url: URL) -> String {
+        url.to_string()
+    }
+}
+
+impl fmt::Display for URL {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}://{}{}", self.protocol, self.host, self.path)?;
+        for (i, (key, value)) in self.query.iter().enumerate() {
+            let separator = if i == 0 { '?' } else { '&' };
+            write!(
+                f,
+                "{}{}={}",
+                separator,
+                percent_encode(key),
+                percent_encode(value)
+            )?;
+        }
+        Ok(())
+    }
 }
 
 /// Represents a matrix with rows, columns, and a two-dimensional data array.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::Matrix;
+///
+/// let mut matrix = Matrix::from_rows(vec![vec![1, 20], vec![300, 4]]).unwrap();
+/// matrix[(1, 1)] = 5;
+/// assert_eq!(matrix.to_string(), "[  1  20]\n[300   5]");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "MatrixFields<T>"))]
 pub struct Matrix<T> {
     rows: usize,
     columns: usize,
     data: Vec<Vec<T>>,
 }
 
-/// Represents a time interval with start and end timestamps.
+/// The unchecked fields of a deserialized `Matrix`, validated before use.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct MatrixFields<T> {
+    rows: usize,
+    columns: usize,
+    data: Vec<Vec<T>>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<MatrixFields<T>> for Matrix<T> {
+    type Error = MatrixError;
+
+    fn try_from(fields: MatrixFields<T>) -> Result<Matrix<T>, MatrixError> {
+        if fields.data.len() != fields.rows
+            || fields.data.iter().any(|row| row.len() != fields.columns)
+        {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        Ok(Matrix {
+            rows: fields.rows,
+            columns: fields.columns,
+            data: fields.data,
+        })
+    }
+}
+
+/// Represents the ways a matrix operation can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatrixError {
+    /// The rows passed to a constructor have different lengths.
+    RaggedRows,
+    /// A row or column index is past the edge of the matrix.
+    IndexOutOfBounds,
+    /// The matrices do not have compatible dimensions for the operation.
+    DimensionMismatch,
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatrixError::RaggedRows => write!(f, "all matrix rows must have the same length"),
+            MatrixError::IndexOutOfBounds => write!(f, "matrix index is out of bounds"),
+            MatrixError::DimensionMismatch => write!(f, "matrix dimensions do not match"),
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
+impl<T: Default + Clone> Matrix<T> {
+    /// Creates a matrix of the given size filled with default values.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Matrix;
+    ///
+    /// let matrix: Matrix<i32> = Matrix::new(2, 3);
+    /// assert_eq!((matrix.rows(), matrix.columns()), (2, 3));
+    /// assert_eq!(matrix[(1, 2)], 0);
+    /// ```
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Matrix {
+            rows,
+            columns,
+            data: vec![vec![T::default(); columns]; rows],
+        }
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Creates a matrix from a list of rows, which must all have the same length.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::{Matrix, MatrixError};
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(matrix[(1, 0)], 3);
+    /// assert_eq!(Matrix::from_rows(vec![vec![1, 2], vec![3]]), Err(MatrixError::RaggedRows));
+    /// ```
+    pub fn from_rows(data: Vec<Vec<T>>) -> Result<Matrix<T>, MatrixError> {
+        let columns = data.first().map_or(0, Vec::len);
+        if data.iter().any(|row| row.len() != columns) {
+            return Err(MatrixError::RaggedRows);
+        }
+        Ok(Matrix {
+            rows: data.len(),
+            columns,
+            data,
+        })
+    }
+
+    /// Creates a matrix of the given size, filling each cell with `f(row, column)`.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Matrix;
+    ///
+    /// let numbered = Matrix::from_fn(2, 3, |row, col| row * 3 + col);
+    /// assert_eq!(numbered, Matrix::from_rows(vec![vec![0, 1, 2], vec![3, 4, 5]]).unwrap());
+    /// ```
+    pub fn from_fn(rows: usize, columns: usize, f: impl Fn(usize, usize) -> T) -> Matrix<T> {
+        Matrix {
+            rows,
+            columns,
+            data: (0..rows)
+                .map(|r| (0..columns).map(|c| f(r, c)).collect())
+                .collect(),
+        }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns a reference to the element at the given position, if it exists.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(matrix.get(0, 1), Some(&2));
+    /// assert_eq!(matrix.get(2, 0), None);
+    /// ```
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        self.data.get(row)?.get(column)
+    }
+
+    /// Replaces the element at the given position.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::{Matrix, MatrixError};
+    ///
+    /// let mut matrix: Matrix<i32> = Matrix::new(2, 3);
+    /// assert_eq!(matrix.set(1, 2, 7), Ok(()));
+    /// assert_eq!(matrix[(1, 2)], 7);
+    /// assert_eq!(matrix.set(0, 3, 1), Err(MatrixError::IndexOutOfBounds));
+    /// ```
+    pub fn set(&mut self, row: usize, column: usize, value: T) -> Result<(), MatrixError> {
+        let cell = self
+            .data
+            .get_mut(row)
+            .and_then(|r| r.get_mut(column))
+            .ok_or(MatrixError::IndexOutOfBounds)?;
+        *cell = value;
+        Ok(())
+    }
+
+    /// Returns the row at the given index, if it exists.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(matrix.row(1), Some(&[3, 4][..]));
+    /// ```
+    pub fn row(&self, index: usize) -> Option<&[T]> {
+        self.data.get(index).map(Vec::as_slice)
+    }
+
+    /// Returns an iterator over the rows, from top to bottom.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// let sums: Vec<i32> = matrix.rows_iter().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(sums, [3, 7]);
+    /// ```
+    pub fn rows_iter(&self) -> impl Iterator<Item = &[T]> {
+        self.data.iter().map(Vec::as_slice)
+    }
+
+    /// Returns references to the elements of the column at the given index, if it exists.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(matrix.col(1), Some(vec![&2, &4]));
+    /// ```
+    pub fn col(&self, index: usize) -> Option<Vec<&T>> {
+        if index >= self.columns {
+            return None;
+        }
+        Some(self.data.iter().map(|row| &row[index]).collect())
+    }
+
+    /// Returns a new matrix of the same size with `f` applied to every element.
+    ///
+    /// Each row is collected from an exact-size iterator, so it is allocated once at full size.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// let halves = matrix.map(|&x| x as f64 / 2.0);
+    /// assert_eq!(halves, Matrix::from_rows(vec![vec![0.5, 1.0], vec![1.5, 2.0]]).unwrap());
+    /// ```
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Matrix<U> {
+        Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data: self
+                .data
+                .iter()
+                .map(|row| row.iter().map(&f).collect())
+                .collect(),
+        }
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Returns a new matrix with rows and columns swapped.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// let expected = Matrix::from_rows(vec![vec![1, 4], vec![2, 5], vec![3, 6]]).unwrap();
+    /// assert_eq!(matrix.transpose(), expected);
+    /// ```
+    pub fn transpose(&self) -> Matrix<T> {
+        Matrix {
+            rows: self.columns,
+            columns: self.rows,
+            data: (0..self.columns)
+                .map(|c| self.data.iter().map(|row| row[c].clone()).collect())
+                .collect(),
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Add<Output = T> + Default + Copy> Matrix<T> {
+    /// Multiplies this matrix by another, which must have as many rows as this one has columns.
+    ///
+    /// The `*` operator does the same but panics on mismatched dimensions.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Matrix;
+    ///
+    /// let a = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// let b = Matrix::from_rows(vec![vec![7, 8], vec![9, 10], vec![11, 12]]).unwrap();
+    /// let product = Matrix::from_rows(vec![vec![58, 64], vec![139, 154]]).unwrap();
+    /// assert_eq!(a.multiply(&b), Ok(product.clone()));
+    /// assert_eq!(&a * &b, product);
+    /// ```
+    pub fn multiply(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.columns != other.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let data = self
+            .data
+            .iter()
+            .map(|row| {
+                (0..other.columns)
+                    .map(|c| {
+                        row.iter()
+                            .zip(&other.data)
+                            .fold(T::default(), |sum, (&a, other_row)| sum + a * other_row[c])
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Matrix {
+            rows: self.rows,
+            columns: other.columns,
+            data,
+        })
+    }
+}
+
+impl<T: Mul<Output = T> + Add<Output = T> + Default + Copy> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Panics if the dimensions are incompatible; use `Matrix::multiply` to handle that case.
+    fn mul(self, other: &Matrix<T>) -> Matrix<T> {
+        self.multiply(other)
+            .expect("matrix dimensions are incompatible for multiplication")
+    }
+}
+
+impl<T: Mul<Output = T> + Add<Output = T> + Default + Copy> Mul for Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Panics if the dimensions are incompatible; use `Matrix::multiply` to handle that case.
+    fn mul(self, other: Matrix<T>) -> Matrix<T> {
+        &self * &other
+    }
+}
+
+impl<T: Default + Clone> Matrix<T> {
+    /// Creates an `n`×`n` identity matrix, using `one` for the diagonal and the default value elsewhere.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Matrix;
+    ///
+    /// let identity = Matrix::identity(2, 1);
+    /// assert_eq!(identity, Matrix::from_rows(vec![vec![1, 0], vec![0, 1]]).unwrap());
+    /// ```
+    pub fn identity(n: usize, one: T) -> Matrix<T> {
+        let mut matrix = Matrix::new(n, n);
+        for i in 0..n {
+            matrix.data[i][i] = one.clone();
+        }
+        matrix
+    }
+}
+
+impl<T: Add<Output = T> + Copy> Matrix<T> {
+    /// Adds two matrices of the same dimensions element-wise.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Matrix;
+    ///
+    /// let a = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// let b = Matrix::from_rows(vec![vec![10, 20], vec![30, 40]]).unwrap();
+    /// assert_eq!(a.add(&b), Matrix::from_rows(vec![vec![11, 22], vec![33, 44]]));
+    /// ```
+    pub fn add(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.rows != other.rows || self.columns != other.columns {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let data = self
+            .data
+            .iter()
+            .zip(&other.data)
+            .map(|(row, other_row)| row.iter().zip(other_row).map(|(&a, &b)| a + b).collect())
+            .collect();
+
+        Ok(Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data,
+        })
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Matrix<T> {
+    /// Multiplies every element by a factor.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(matrix.scale(3), Matrix::from_rows(vec![vec![3, 6], vec![9, 12]]).unwrap());
+    /// ```
+    pub fn scale(&self, factor: T) -> Matrix<T> {
+        Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data: self
+                .data
+                .iter()
+                .map(|row| row.iter().map(|&x| x * factor).collect())
+                .collect(),
+        }
+    }
+}
+
+impl Matrix<f64> {
+    /// Computes the determinant of a square matrix using LU decomposition with partial pivoting.
+    ///
+    /// Rounding errors accumulate with size, so a singular matrix may give a value that is
+    /// only close to zero; compare against a tolerance rather than testing for exactly 0.0.
+    ///
+    /// ```rust
+    /// use qol_rs::structs_and_enums::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![3.0, 8.0], vec![4.0, 6.0]]).unwrap();
+    /// assert!((matrix.determinant().unwrap() + 14.0).abs() < 1e-9);
+    /// ```
+    pub fn determinant(&self) -> Result<f64, MatrixError> {
+        if self.rows != self.columns {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let n = self.rows;
+        let mut lu = self.data.clone();
+        let mut det = 1.0;
+        for col in 0..n {
+            let pivot = (col..n)
+                .max_by(|&a, &b| lu[a][col].abs().total_cmp(&lu[b][col].abs()))
+                .unwrap_or(col);
+            if lu[pivot][col] == 0.0 {
+                return Ok(0.0);
+            }
+            if pivot != col {
+                lu.swap(pivot, col);
+                det = -det;
+            }
+
+            det *= lu[col][col];
+            let (upper, lower) = lu.split_at_mut(col + 1);
+            let pivot_row = &upper[col];
+            for row in lower {
+                let factor = row[col] / pivot_row[col];
+                for (value, &pivot_value) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                    *value -= factor * pivot_value;
+                }
+            }
+        }
+        Ok(det)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Matrix<T> {
+    /// Prints each row in brackets on its own line, with columns right-aligned.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cells: Vec<Vec<String>> = self
+            .data
+            .iter()
+            .map(|row| row.iter().map(ToString::to_string).collect())
+            .collect();
+        let width = cells.iter().flatten().map(String::len).max().unwrap_or(0);
+
+        for (i, row) in cells.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let row: Vec<String> = row.iter().map(|cell| format!("{:>width$}", cell)).collect();
+            write!(f, "[{}]", row.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        &self.data[row][column]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        &mut self.data[row][column]
+    }
+}
+
+/// Represents a half-open time interval `[start, end)` with start and end timestamps.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::{IntervalError, TimeInterval};
+///
+/// let morning = TimeInterval::new(8, 12).unwrap();
+/// let lunch = TimeInterval::new(11, 13).unwrap();
+/// let afternoon = TimeInterval::new(12, 17).unwrap();
+/// let night = TimeInterval::new(20, 23).unwrap();
+///
+/// assert_eq!(TimeInterval::new(5, 4), Err(IntervalError::EndBeforeStart));
+/// assert_eq!(morning.duration(), 4);
+/// assert!(morning.contains(8) && !morning.contains(12));
+///
+/// assert!(morning.overlaps(&lunch));
+/// assert!(!morning.overlaps(&afternoon));
+/// assert!(!morning.overlaps(&night));
+///
+/// assert_eq!(morning.merge(&lunch), Some(TimeInterval::new(8, 13).unwrap()));
+/// assert_eq!(morning.merge(&afternoon), Some(TimeInterval::new(8, 17).unwrap()));
+/// assert_eq!(morning.merge(&night), None);
+///
+/// # #[cfg(feature = "serde")]
+/// # {
+/// let json = serde_json::to_string(&morning).unwrap();
+/// assert_eq!(json, r#"{"start":8,"end":12}"#);
+/// assert_eq!(serde_json::from_str::<TimeInterval>(&json).unwrap(), morning);
+/// assert!(serde_json::from_str::<TimeInterval>(r#"{"start":5,"end":4}"#).is_err());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "TimeIntervalFields"))]
 pub struct TimeInterval {
     start: u64,
     end: u64,
 }
 
-/// Represents a set of options.
+/// The unchecked fields of a deserialized `TimeInterval`, validated before use.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct TimeIntervalFields {
+    start: u64,
+    end: u64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TimeIntervalFields> for TimeInterval {
+    type Error = IntervalError;
+
+    fn try_from(fields: TimeIntervalFields) -> Result<TimeInterval, IntervalError> {
+        TimeInterval::new(fields.start, fields.end)
+    }
+}
+
+/// Represents the ways building a time interval can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntervalError {
+    /// The end timestamp is earlier than the start timestamp.
+    EndBeforeStart,
+}
+
+impl fmt::Display for IntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IntervalError::EndBeforeStart => write!(f, "interval end is before its start"),
+        }
+    }
+}
+
+impl std::error::Error for IntervalError {}
+
+impl TimeInterval {
+    /// Creates a new interval, rejecting an end earlier than the start.
+    pub fn new(start: u64, end: u64) -> Result<TimeInterval, IntervalError> {
+        if end < start {
+            return Err(IntervalError::EndBeforeStart);
+        }
+        Ok(TimeInterval { start, end })
+    }
+
+    /// Returns the start timestamp.
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// Returns the end timestamp.
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    /// Returns the length of the interval.
+    pub fn duration(&self) -> u64 {
+        self.end - self.start
+    }
+
+    /// Returns true if the timestamp falls within the interval.
+    pub fn contains(&self, t: u64) -> bool {
+        t >= self.start && t < self.end
+    }
+
+    /// Returns true if the intervals share any time; touching intervals do not overlap.
+    pub fn overlaps(&self, other: &TimeInterval) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Returns the union of two overlapping or adjacent intervals, or None if there is a gap.
+    pub fn merge(&self, other: &TimeInterval) -> Option<TimeInterval> {
+        if self.start <= other.end && other.start <= self.end {
+            Some(TimeInterval {
+                start: self.start.min(other.start),
+                end: self.end.max(other.end),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Represents a set of options, kept in insertion order.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::OptionSet;
+///
+/// let mut set = OptionSet::new();
+/// assert!(set.insert("verbose"));
+/// assert!(!set.insert("verbose"));
+/// assert_eq!(set.len(), 1);
+///
+/// assert!(!set.toggle("verbose"));
+/// assert!(!set.contains(&"verbose"));
+/// assert!(set.toggle("quiet"));
+/// assert!(set.contains(&"quiet"));
+/// assert!(set.remove(&"quiet"));
+/// assert!(set.is_empty());
+///
+/// let set: OptionSet<_> = vec![1, 2, 1, 3, 2].into_iter().collect();
+/// assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+///
+/// let a: OptionSet<_> = [1, 2].into_iter().collect();
+/// let b: OptionSet<_> = [2, 3].into_iter().collect();
+/// assert_eq!(a.union(&b).into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+/// assert_eq!(a.intersection(&b).into_iter().collect::<Vec<_>>(), vec![2]);
+/// assert_eq!(a.difference(&b).into_iter().collect::<Vec<_>>(), vec![1]);
+/// assert_eq!(a.union(&b), b.union(&a));
+///
+/// # #[cfg(feature = "serde")]
+/// # {
+/// assert_eq!(serde_json::to_string(&a).unwrap(), "[1,2]");
+/// assert_eq!(serde_json::from_str::<OptionSet<i32>>("[1,2,1]").unwrap(), a);
+/// # }
+/// assert!(a.intersection(&b).is_subset_of(&a));
+/// assert!(!a.is_subset_of(&b));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        from = "Vec<T>",
+        into = "Vec<T>",
+        bound(
+            serialize = "T: Clone + serde::Serialize",
+            deserialize = "T: PartialEq + serde::Deserialize<'de>"
+        )
+    )
+)]
 pub struct OptionSet<T> {
     options: Vec<T>,
 }
 
+impl<T> OptionSet<T> {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        OptionSet {
+            options: Vec::new(),
+        }
+    }
+
+    /// Returns the number of options in the set.
+    pub fn len(&self) -> usize {
+        self.options.len()
+    }
+
+    /// Returns true if the set has no options.
+    pub fn is_empty(&self) -> bool {
+        self.options.is_empty()
+    }
+
+    /// Returns an iterator over the options in insertion order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.options.iter()
+    }
+}
+
+impl<T: PartialEq> OptionSet<T> {
+    /// Returns true if the option is in the set.
+    pub fn contains(&self, item: &T) -> bool {
+        self.options.contains(item)
+    }
+
+    /// Adds an option, returning false if it was already present.
+    pub fn insert(&mut self, item: T) -> bool {
+        if self.contains(&item) {
+            return false;
+        }
+        self.options.push(item);
+        true
+    }
+
+    /// Removes an option, returning false if it was not present.
+    pub fn remove(&mut self, item: &T) -> bool {
+        match self.options.iter().position(|option| option == item) {
+            Some(index) => {
+                self.options.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Flips the membership of an option, returning true if it is now present.
+    pub fn toggle(&mut self, item: T) -> bool {
+        !self.remove(&item) && self.insert(item)
+    }
+
+    /// Returns true if every option in this set is also in `other`.
+    pub fn is_subset_of(&self, other: &OptionSet<T>) -> bool {
+        self.options.iter().all(|item| other.contains(item))
+    }
+}
+
+impl<T: PartialEq + Clone> OptionSet<T> {
+    /// Returns the options in either set, with this set's options first.
+    pub fn union(&self, other: &OptionSet<T>) -> OptionSet<T> {
+        self.options.iter().chain(&other.options).cloned().collect()
+    }
+
+    /// Returns the options in both sets, in this set's order.
+    pub fn intersection(&self, other: &OptionSet<T>) -> OptionSet<T> {
+        self.options
+            .iter()
+            .filter(|item| other.contains(item))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the options in this set but not in `other`, in this set's order.
+    pub fn difference(&self, other: &OptionSet<T>) -> OptionSet<T> {
+        self.options
+            .iter()
+            .filter(|item| !other.contains(item))
+            .cloned()
+            .collect()
+    }
+}
+
+impl<T: PartialEq> PartialEq for OptionSet<T> {
+    /// Two sets are equal if they hold the same options, regardless of insertion order.
+    fn eq(&self, other: &OptionSet<T>) -> bool {
+        self.len() == other.len() && self.is_subset_of(other)
+    }
+}
+
+impl<T: Eq> Eq for OptionSet<T> {}
+
+impl<T> Default for OptionSet<T> {
+    fn default() -> Self {
+        OptionSet::new()
+    }
+}
+
+impl<T> IntoIterator for OptionSet<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.options.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a OptionSet<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.options.iter()
+    }
+}
+
+impl<T: PartialEq> From<Vec<T>> for OptionSet<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T> From<OptionSet<T>> for Vec<T> {
+    fn from(set: OptionSet<T>) -> Self {
+        set.options
+    }
+}
+
+impl<T: PartialEq> FromIterator<T> for OptionSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = OptionSet::new();
+        for item in iter {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+/// Represents a set of `usize` indices stored as bits, one per possible index.
+///
+/// Memory grows with the largest index inserted, which makes it far more compact than a
+/// `HashSet<usize>` for dense sets of small indices.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::BitSet;
+///
+/// let mut a = BitSet::new();
+/// for index in [0, 63, 64, 127] {
+///     assert!(a.insert(index));
+/// }
+/// assert!(!a.insert(64));
+/// assert!(a.contains(63) && a.contains(64) && a.contains(127));
+/// assert!(!a.contains(1) && !a.contains(128));
+/// assert_eq!(a.count_ones(), 4);
+///
+/// let b: BitSet = [1, 64, 127, 200].into_iter().collect();
+/// assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), vec![0, 1, 63, 64, 127, 200]);
+/// assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), vec![64, 127]);
+///
+/// assert!(a.remove(127));
+/// assert!(!a.remove(127));
+/// assert_eq!(a, [0, 63, 64].into_iter().collect());
+/// assert!(BitSet::new().is_empty());
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        BitSet { words: Vec::new() }
+    }
+
+    /// Returns the word index and bit mask for an index.
+    fn locate(index: usize) -> (usize, u64) {
+        (index / 64, 1 << (index % 64))
+    }
+
+    /// Adds an index, returning false if it was already present.
+    pub fn insert(&mut self, index: usize) -> bool {
+        let (word, mask) = BitSet::locate(index);
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let absent = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        absent
+    }
+
+    /// Removes an index, returning false if it was not present.
+    pub fn remove(&mut self, index: usize) -> bool {
+        let (word, mask) = BitSet::locate(index);
+        match self.words.get_mut(word) {
+            Some(bits) if *bits & mask != 0 => {
+                *bits &= !mask;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns true if the index is in the set.
+    pub fn contains(&self, index: usize) -> bool {
+        let (word, mask) = BitSet::locate(index);
+        self.words.get(word).is_some_and(|bits| bits & mask != 0)
+    }
+
+    /// Returns the number of indices in the set.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|bits| bits.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns true if the set has no indices.
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&bits| bits == 0)
+    }
+
+    /// Returns the indices in either set.
+    pub fn union(&self, other: &BitSet) -> BitSet {
+        let (longer, shorter) = if self.words.len() >= other.words.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut words = longer.words.clone();
+        for (bits, other_bits) in words.iter_mut().zip(&shorter.words) {
+            *bits |= other_bits;
+        }
+        BitSet { words }
+    }
+
+    /// Returns the indices in both sets.
+    pub fn intersection(&self, other: &BitSet) -> BitSet {
+        BitSet {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(a, b)| a & b)
+                .collect(),
+        }
+    }
+
+    /// Returns an iterator over the indices in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(word, &bits)| {
+            (0..64)
+                .filter(move |bit| bits & (1 << bit) != 0)
+                .map(move |bit| word * 64 + bit)
+        })
+    }
+}
+
+impl PartialEq for BitSet {
+    /// Two sets are equal if they hold the same indices, regardless of allocated capacity.
+    fn eq(&self, other: &BitSet) -> bool {
+        let len = self.words.len().max(other.words.len());
+        (0..len).all(|i| self.words.get(i).unwrap_or(&0) == other.words.get(i).unwrap_or(&0))
+    }
+}
+
+impl Eq for BitSet {}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = BitSet::new();
+        for index in iter {
+            set.insert(index);
+        }
+        set
+    }
+}
+
 /// Represents a circle with a radius and a center point.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::{Circle, Point2D, PositionedRect};
+///
+/// let circle = Circle::new(Point2D::new(0.0, 0.0), 5.0);
+/// assert_eq!(circle.area(), std::f64::consts::PI * 25.0);
+/// assert_eq!(circle.circumference(), std::f64::consts::PI * 10.0);
+/// assert!(circle.contains(&Point2D::new(3.0, 4.0)));
+/// assert!(!circle.contains(&Point2D::new(4.0, 4.0)));
+/// assert_eq!(circle.bounding_rect(), PositionedRect::new(Point2D::new(-5.0, -5.0), 10.0, 10.0));
+///
+/// assert!(circle.intersects(&Circle::new(Point2D::new(8.0, 0.0), 4.0)));
+/// assert!(!circle.intersects(&Circle::new(Point2D::new(20.0, 0.0), 4.0)));
+///
+/// assert_eq!(circle.to_string(), "Circle((0, 0), 5)");
+/// assert_eq!(format!("{:.1}", Circle::new(Point2D::new(1.0, 2.0), 0.26)), "Circle((1.0, 2.0), 0.3)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Circle {
     radius: f64,
     center: Point2D,
 }
 
-/// Represents a priority queue.
+impl Circle {
+    /// Creates a new circle from its center and radius.
+    pub fn new(center: Point2D, radius: f64) -> Self {
+        Circle { radius, center }
+    }
+
+    /// Returns the center of the circle.
+    pub fn center(&self) -> Point2D {
+        self.center
+    }
+
+    /// Returns the radius of the circle.
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Returns the area of the circle.
+    pub fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+
+    /// Returns the circumference of the circle.
+    pub fn circumference(&self) -> f64 {
+        2.0 * std::f64::consts::PI * self.radius
+    }
+
+    /// Returns true if the point lies inside the circle or on its boundary.
+    pub fn contains(&self, p: &Point2D) -> bool {
+        self.center.distance_to(p) <= self.radius + EPSILON
+    }
+
+    /// Returns the smallest axis-aligned rectangle containing the circle.
+    pub fn bounding_rect(&self) -> PositionedRect {
+        let corner = Point2D::new(self.center.x - self.radius, self.center.y - self.radius);
+        PositionedRect::new(corner, 2.0 * self.radius, 2.0 * self.radius)
+    }
+
+    /// Returns true if the circles overlap or touch.
+    pub fn intersects(&self, other: &Circle) -> bool {
+        self.center.distance_to(&other.center) <= self.radius + other.radius + EPSILON
+    }
+}
+
+impl fmt::Display for Circle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Circle(")?;
+        fmt::Display::fmt(&self.center, f)?;
+        write!(f, ", ")?;
+        write_float(f, self.radius)?;
+        write!(f, ")")
+    }
+}
+
+/// Represents a priority queue, implemented as a binary max-heap.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::PriorityQueue;
+///
+/// let mut queue = PriorityQueue::new();
+/// for value in [3, 1, 4, 1, 5, 9, 2, 6] {
+///     queue.push(value);
+/// }
+/// assert_eq!(queue.len(), 8);
+/// assert_eq!(queue.peek(), Some(&9));
+///
+/// let mut popped = Vec::new();
+/// while let Some(value) = queue.pop() {
+///     popped.push(value);
+/// }
+/// assert_eq!(popped, vec![9, 6, 5, 4, 3, 2, 1, 1]);
+/// assert!(queue.is_empty());
+///
+/// queue.push(2);
+/// queue.push(3);
+/// queue.push(1);
+/// assert_eq!(queue.into_sorted_vec(), vec![3, 2, 1]);
+/// ```
+#[derive(Debug, Clone)]
 pub struct PriorityQueue<T> {
     items: Vec<T>,
 }
 
+impl<T: Ord> PriorityQueue<T> {
+    /// Creates an empty priority queue.
+    pub fn new() -> Self {
+        PriorityQueue { items: Vec::new() }
+    }
+
+    /// Returns the number of items in the queue.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if the queue has no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the greatest item without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    /// Adds an item to the queue.
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+        sift_up(&mut self.items, T::cmp);
+    }
+
+    /// Removes and returns the greatest item.
+    pub fn pop(&mut self) -> Option<T> {
+        pop_heap(&mut self.items, T::cmp)
+    }
+
+    /// Consumes the queue and returns its items from greatest to least.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        std::iter::from_fn(|| self.pop()).collect()
+    }
+}
+
+impl<T> PriorityQueue<T> {
+    /// Creates an empty priority queue ordered by a custom comparator instead of `Ord`.
+    ///
+    /// Items the comparator considers greater are popped first, so reversing the comparison
+    /// produces a min-heap.
+    pub fn with_comparator<F>(cmp: F) -> ComparatorPriorityQueue<T, F>
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        ComparatorPriorityQueue::new(cmp)
+    }
+}
+
+/// Restores the heap invariant after pushing an item onto the end of `items`.
+fn sift_up<T>(items: &mut [T], cmp: impl Fn(&T, &T) -> Ordering) {
+    let mut child = items.len().saturating_sub(1);
+    while child > 0 {
+        let parent = (child - 1) / 2;
+        if cmp(&items[child], &items[parent]) != Ordering::Greater {
+            break;
+        }
+        items.swap(child, parent);
+        child = parent;
+    }
+}
+
+/// Removes the top of the heap and restores the heap invariant.
+fn pop_heap<T>(items: &mut Vec<T>, cmp: impl Fn(&T, &T) -> Ordering) -> Option<T> {
+    if items.is_empty() {
+        return None;
+    }
+    let top = items.swap_remove(0);
+
+    let mut parent = 0;
+    loop {
+        let (left, right) = (2 * parent + 1, 2 * parent + 2);
+        let mut largest = parent;
+        if left < items.len() && cmp(&items[left], &items[largest]) == Ordering::Greater {
+            largest = left;
+        }
+        if right < items.len() && cmp(&items[right], &items[largest]) == Ordering::Greater {
+            largest = right;
+        }
+        if largest == parent {
+            break;
+        }
+        items.swap(parent, largest);
+        parent = largest;
+    }
+
+    Some(top)
+}
+
+impl<T: Ord> Default for PriorityQueue<T> {
+    fn default() -> Self {
+        PriorityQueue::new()
+    }
+}
+
+/// Represents a priority queue ordered by a custom comparator.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::PriorityQueue;
+///
+/// let mut min_heap = PriorityQueue::with_comparator(|a: &i32, b: &i32| b.cmp(a));
+/// for value in [3, 1, 4, 1, 5] {
+///     min_heap.push(value);
+/// }
+/// assert_eq!(min_heap.peek(), Some(&1));
+/// assert_eq!(min_heap.pop(), Some(1));
+/// assert_eq!(min_heap.into_sorted_vec(), vec![1, 3, 4, 5]);
+///
+/// let mut by_len = PriorityQueue::with_comparator(|a: &&str, b: &&str| a.len().cmp(&b.len()));
+/// by_len.push("aa");
+/// by_len.push("a");
+/// by_len.push("aaa");
+/// assert_eq!(by_len.pop(), Some("aaa"));
+/// ```
+pub struct ComparatorPriorityQueue<T, F> {
+    items: Vec<T>,
+    cmp: F,
+}
+
+impl<T, F> ComparatorPriorityQueue<T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    /// Creates an empty priority queue where items the comparator considers greater are popped first.
+    pub fn new(cmp: F) -> Self {
+        ComparatorPriorityQueue {
+            items: Vec::new(),
+            cmp,
+        }
+    }
+
+    /// Returns the number of items in the queue.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if the queue has no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the highest priority item without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    /// Adds an item to the queue.
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+        sift_up(&mut self.items, &self.cmp);
+    }
+
+    /// Removes and returns the highest priority item.
+    pub fn pop(&mut self) -> Option<T> {
+        pop_heap(&mut self.items, &self.cmp)
+    }
+
+    /// Consumes the queue and returns its items in priority order.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        std::iter::from_fn(|| self.pop()).collect()
+    }
+}
+
 /// Represents a node in a linked list.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Node<T> {
     data: T,
     next: Option<Box<Node<T>>>,
 }
 
+impl<T> Node<T> {
+    /// Creates a node with no successor.
+    pub fn new(data: T) -> Self {
+        Node { data, next: None }
+    }
+
+    /// Returns a reference to the node's data.
+    pub fn data(&self) -> &T {
+        &self.data
+    }
+
+    /// Returns the next node, if any.
+    pub fn next(&self) -> Option<&Node<T>> {
+        self.next.as_deref()
+    }
+}
+
+/// Represents a singly linked list built from `Node`s.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::LinkedList;
+///
+/// let mut list = LinkedList::from_vec(vec![2, 3]);
+/// list.push_front(1);
+/// list.push_back(4);
+/// assert_eq!(list.len(), 4);
+/// assert_eq!(list.to_vec(), vec![1, 2, 3, 4]);
+/// assert_eq!(list.iter().sum::<i32>(), 10);
+///
+/// assert_eq!(list.pop_front(), Some(1));
+/// assert_eq!(list.to_vec(), vec![2, 3, 4]);
+///
+/// let mut list = LinkedList::from_vec(vec![1, 2, 3]);
+/// list.reverse();
+/// assert_eq!(list.to_vec(), vec![3, 2, 1]);
+/// assert_eq!(list.find(|x| x % 2 == 0), Some(&2));
+/// assert_eq!(list.find(|x| *x > 5), None);
+/// assert_eq!(list.clone(), list);
+/// assert_eq!(format!("{:?}", list), "[3, 2, 1]");
+///
+/// // Dropping a long list does not overflow the stack.
+/// drop(LinkedList::from_vec((0..1_000_000).collect()));
+/// ```
+pub struct LinkedList<T> {
+    head: Option<Box<Node<T>>>,
+    len: usize,
+}
+
+impl<T> LinkedList<T> {
+    /// Creates an empty list.
+    pub fn new() -> Self {
+        LinkedList { head: None, len: 0 }
+    }
+
+    /// Creates a list holding the elements of a Vec in order.
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        let mut list = LinkedList::new();
+        for item in vec.into_iter().rev() {
+            list.push_front(item);
+        }
+        list
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Adds an element to the front of the list.
+    pub fn push_front(&mut self, data: T) {
+        let next = self.head.take();
+        self.head = Some(Box::new(Node { data, next }));
+        self.len += 1;
+    }
+
+    /// Adds an element to the back of the list.
+    pub fn push_back(&mut self, data: T) {
+        let mut cursor = &mut self.head;
+        while let Some(node) = cursor {
+            cursor = &mut node.next;
+        }
+        *cursor = Some(Box::new(Node::new(data)));
+        self.len += 1;
+    }
+
+    /// Removes and returns the first element of the list.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|node| {
+            self.head = node.next;
+            self.len -= 1;
+            node.data
+        })
+    }
+
+    /// Reverses the list in place by relinking its nodes.
+    pub fn reverse(&mut self) {
+        let mut reversed = None;
+        let mut current = self.head.take();
+        while let Some(mut node) = current {
+            current = node.next.take();
+            node.next = reversed;
+            reversed = Some(node);
+        }
+        self.head = reversed;
+    }
+
+    /// Returns the first element matching the predicate.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<&T> {
+        self.iter().find(|item| predicate(item))
+    }
+
+    /// Returns an iterator over references to the elements.
+    pub fn iter(&self) -> LinkedListIter<'_, T> {
+        LinkedListIter {
+            next: self.head.as_deref(),
+        }
+    }
+}
+
+impl<T: Clone> LinkedList<T> {
+    /// Copies the elements of the list into a Vec.
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        LinkedList::from_vec(self.to_vec())
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &LinkedList<T>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        LinkedList::new()
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        let mut next = self.head.take();
+        while let Some(mut node) = next {
+            next = node.next.take();
+        }
+    }
+}
+
+/// An iterator over references to the elements of a `LinkedList`.
+pub struct LinkedListIter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for LinkedListIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.data
+        })
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = LinkedListIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Represents a fixed-capacity circular buffer that overwrites its oldest element when full.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::RingBuffer;
+///
+/// let mut buffer = RingBuffer::new(3);
+/// assert_eq!(buffer.push(1), None);
+/// assert_eq!(buffer.push(2), None);
+/// assert_eq!(buffer.push(3), None);
+/// assert!(buffer.is_full());
+///
+/// assert_eq!(buffer.push(4), Some(1));
+/// assert_eq!(buffer.push(5), Some(2));
+/// assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+/// assert_eq!(buffer.len(), 3);
+///
+/// assert_eq!(buffer.pop(), Some(3));
+/// assert_eq!(buffer.pop(), Some(4));
+/// assert!(!buffer.is_full());
+/// buffer.push(6);
+/// assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![5, 6]);
+///
+/// assert_eq!(buffer.pop(), Some(5));
+/// assert_eq!(buffer.pop(), Some(6));
+/// assert_eq!(buffer.pop(), None);
+/// assert!(buffer.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RingBuffer<T> {
+    /// The slots of the buffer; `None` marks an empty slot.
+    slots: Vec<Option<T>>,
+    /// The index of the oldest element.
+    head: usize,
+    /// The index the next pushed element is written to.
+    tail: usize,
+    /// The number of occupied slots.
+    len: usize,
+}
+
+impl<T> RingBuffer<T> {
+    /// Creates an empty buffer holding at most `capacity` elements. Panics if `capacity` is 0.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "ring buffer capacity must be non-zero");
+        RingBuffer {
+            slots: std::iter::repeat_with(|| None).take(capacity).collect(),
+            head: 0,
+            tail: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the maximum number of elements the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns the number of elements in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the buffer has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the next push will overwrite the oldest element.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Adds an element as the newest, returning the oldest element if it was overwritten.
+    pub fn push(&mut self, item: T) -> Option<T> {
+        let evicted = self.slots[self.tail].replace(item);
+        self.tail = (self.tail + 1) % self.capacity();
+        if evicted.is_some() {
+            self.head = self.tail;
+        } else {
+            self.len += 1;
+        }
+        evicted
+    }
+
+    /// Removes and returns the oldest element.
+    pub fn pop(&mut self) -> Option<T> {
+        let item = self.slots[self.head].take()?;
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+        Some(item)
+    }
+
+    /// Returns an iterator over the elements from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let (wrapped, start) = self.slots.split_at(self.head);
+        start.iter().chain(wrapped).flatten()
+    }
+}
+
+/// Represents a last-in, first-out stack.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::Stack;
+///
+/// let mut stack: Stack<_> = [1, 2].into_iter().collect();
+/// stack.push(3);
+/// assert_eq!(stack.len(), 3);
+/// assert_eq!(stack.peek(), Some(&3));
+/// assert_eq!(stack.pop(), Some(3));
+/// assert_eq!(stack.pop(), Some(2));
+///
+/// stack.push(4);
+/// assert_eq!(stack.into_iter().collect::<Vec<_>>(), vec![4, 1]);
+/// assert!(Stack::<i32>::new().is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    /// Creates an empty stack.
+    pub fn new() -> Self {
+        Stack { items: Vec::new() }
+    }
+
+    /// Adds an element to the top of the stack.
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    /// Removes and returns the element on top of the stack.
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    /// Returns a reference to the element on top of the stack without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    /// Returns the number of elements in the stack.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if the stack has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Stack::new()
+    }
+}
+
+impl<T> FromIterator<T> for Stack<T> {
+    /// Pushes the elements in order, so the last one ends up on top.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Stack {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = std::iter::Rev<std::vec::IntoIter<T>>;
+
+    /// Yields the elements in the order they would be popped, top first.
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter().rev()
+    }
+}
+
+/// Represents a first-in, first-out queue.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::Queue;
+///
+/// let mut queue: Queue<_> = [1, 2].into_iter().collect();
+/// queue.push(3);
+/// assert_eq!(queue.len(), 3);
+/// assert_eq!(queue.peek(), Some(&1));
+/// assert_eq!(queue.pop(), Some(1));
+/// assert_eq!(queue.pop(), Some(2));
+///
+/// queue.push(4);
+/// assert_eq!(queue.into_iter().collect::<Vec<_>>(), vec![3, 4]);
+/// assert!(Queue::<i32>::new().is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Queue<T> {
+    items: VecDeque<T>,
+}
+
+impl<T> Queue<T> {
+    /// Creates an empty queue.
+    pub fn new() -> Self {
+        Queue {
+            items: VecDeque::new(),
+        }
+    }
+
+    /// Adds an element to the back of the queue.
+    pub fn push(&mut self, item: T) {
+        self.items.push_back(item);
+    }
+
+    /// Removes and returns the element at the front of the queue.
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop_front()
+    }
+
+    /// Returns a reference to the element at the front of the queue without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.items.front()
+    }
+
+    /// Returns the number of elements in the queue.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if the queue has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Self {
+        Queue::new()
+    }
+}
+
+impl<T> FromIterator<T> for Queue<T> {
+    /// Enqueues the elements in order, so the first one ends up at the front.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Queue {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> IntoIterator for Queue<T> {
+    type Item = T;
+    type IntoIter = std::collections::vec_deque::IntoIter<T>;
+
+    /// Yields the elements in the order they would be popped, front first.
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
 /// Represents a binary tree with leaf nodes and internal nodes.
+///
+/// Only leaves carry values, so every traversal order yields the leaves from left to right;
+/// the orders differ only in when the value-less internal nodes are visited.
+///
+/// The traversals, `height`, `leaf_count` and `map` use explicit stacks, but the derived
+/// impls and dropping still recurse, so they can overflow the stack on very deep trees.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::BinaryTree;
+///
+/// let tree = BinaryTree::Node {
+///     left: Box::new(BinaryTree::Leaf(1)),
+///     right: Box::new(BinaryTree::Node {
+///         left: Box::new(BinaryTree::Leaf(2)),
+///         right: Box::new(BinaryTree::Leaf(3)),
+///     }),
+/// };
+///
+/// assert_eq!(tree.in_order(), vec![&1, &2, &3]);
+/// assert_eq!(tree.pre_order(), vec![&1, &2, &3]);
+/// assert_eq!(tree.post_order(), vec![&1, &2, &3]);
+/// assert_eq!(tree.height(), 3);
+/// assert_eq!(tree.leaf_count(), 3);
+///
+/// let doubled = tree.map(|x| x * 2);
+/// assert_eq!(doubled.in_order(), vec![&2, &4, &6]);
+/// assert_eq!(doubled.height(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BinaryTree<T> {
     Leaf(T),
     Node {
@@ -87,7 +3009,189 @@ pub enum BinaryTree<T> {
     },
 }
 
+impl<T> BinaryTree<T> {
+    /// Collects the leaf values from left to right using an explicit stack.
+    fn leaves(&self) -> Vec<&T> {
+        let mut leaves = Vec::new();
+        let mut stack = vec![self];
+        while let Some(tree) = stack.pop() {
+            match tree {
+                BinaryTree::Leaf(value) => leaves.push(value),
+                BinaryTree::Node { left, right } => {
+                    stack.push(right);
+                    stack.push(left);
+                }
+            }
+        }
+        leaves
+    }
+
+    /// Returns the leaf values visited in in-order (left, node, right).
+    pub fn in_order(&self) -> Vec<&T> {
+        self.leaves()
+    }
+
+    /// Returns the leaf values visited in pre-order (node, left, right).
+    pub fn pre_order(&self) -> Vec<&T> {
+        self.leaves()
+    }
+
+    /// Returns the leaf values visited in post-order (left, right, node).
+    pub fn post_order(&self) -> Vec<&T> {
+        self.leaves()
+    }
+
+    /// Returns the number of levels in the tree, where a single leaf has height 1.
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut stack = vec![(self, 1)];
+        while let Some((tree, depth)) = stack.pop() {
+            height = height.max(depth);
+            if let BinaryTree::Node { left, right } = tree {
+                stack.push((left, depth + 1));
+                stack.push((right, depth + 1));
+            }
+        }
+        height
+    }
+
+    /// Returns the number of leaves in the tree.
+    pub fn leaf_count(&self) -> usize {
+        self.leaves().len()
+    }
+
+    /// Applies a function to every leaf value, preserving the shape of the tree.
+    ///
+    /// Leaves are visited left to right, using explicit stacks instead of recursion.
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> BinaryTree<U> {
+        enum Step<T> {
+            Visit(BinaryTree<T>),
+            Join,
+        }
+
+        let mut steps = vec![Step::Visit(self)];
+        let mut mapped: Vec<BinaryTree<U>> = Vec::new();
+        while let Some(step) = steps.pop() {
+            match step {
+                Step::Visit(BinaryTree::Leaf(value)) => mapped.push(BinaryTree::Leaf(f(value))),
+                Step::Visit(BinaryTree::Node { left, right }) => {
+                    steps.push(Step::Join);
+                    steps.push(Step::Visit(*right));
+                    steps.push(Step::Visit(*left));
+                }
+                Step::Join => {
+                    let right = mapped
+                        .pop()
+                        .expect("right subtree is mapped before joining");
+                    let left = mapped.pop().expect("left subtree is mapped before joining");
+                    mapped.push(BinaryTree::Node {
+                        left: Box::new(left),
+                        right: Box::new(right),
+                    });
+                }
+            }
+        }
+        mapped.pop().expect("the root is always mapped")
+    }
+}
+
+/// Represents a binary search tree that ignores duplicate values.
+///
+/// `BinaryTree` only stores values in its leaves, so it cannot order values at internal
+/// nodes; this tree uses its own nodes that each carry a value.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::BinarySearchTree;
+///
+/// let mut tree = BinarySearchTree::new();
+/// for value in [5, 3, 8, 1, 4, 8, 3] {
+///     tree.insert(value);
+/// }
+/// assert_eq!(tree.len(), 5);
+/// assert!(tree.contains(&4));
+/// assert!(!tree.contains(&7));
+/// ```
+pub struct BinarySearchTree<T> {
+    root: Option<Box<SearchNode<T>>>,
+    len: usize,
+}
+
+struct SearchNode<T> {
+    value: T,
+    left: Option<Box<SearchNode<T>>>,
+    right: Option<Box<SearchNode<T>>>,
+}
+
+impl<T: Ord> BinarySearchTree<T> {
+    /// Creates an empty tree.
+    pub fn new() -> Self {
+        BinarySearchTree { root: None, len: 0 }
+    }
+
+    /// Returns the number of values in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the tree has no values.
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Adds a value to the tree, returning false if it was already present.
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut cursor = &mut self.root;
+        while let Some(node) = cursor {
+            cursor = match value.cmp(&node.value) {
+                Ordering::Less => &mut node.left,
+                Ordering::Greater => &mut node.right,
+                Ordering::Equal => return false,
+            };
+        }
+        *cursor = Some(Box::new(SearchNode {
+            value,
+            left: None,
+            right: None,
+        }));
+        self.len += 1;
+        true
+    }
+
+    /// Returns true if the value is in the tree.
+    pub fn contains(&self, value: &T) -> bool {
+        let mut cursor = self.root.as_deref();
+        while let Some(node) = cursor {
+            cursor = match value.cmp(&node.value) {
+                Ordering::Less => node.left.as_deref(),
+                Ordering::Greater => node.right.as_deref(),
+                Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+}
+
+impl<T: Ord> Default for BinarySearchTree<T> {
+    fn default() -> Self {
+        BinarySearchTree::new()
+    }
+}
+
+impl<T> Drop for BinarySearchTree<T> {
+    fn drop(&mut self) {
+        let mut stack: Vec<Box<SearchNode<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left.take());
+            stack.extend(node.right.take());
+        }
+    }
+}
+
 /// Represents a state machine with different states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum State {
     /// The initial state.
     Initial,
@@ -97,9 +3201,469 @@ pub enum State {
     Completed,
 }
 
+/// Represents an attempt to move between two states that are not directly connected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TransitionError {
+    /// The state the machine was in.
+    pub from: State,
+    /// The state the transition tried to reach.
+    pub to: State,
+}
+
+impl fmt::Display for TransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cannot transition from {:?} to {:?}", self.from, self.to)
+    }
+}
+
+impl std::error::Error for TransitionError {}
+
+/// A callback invoked with the old and new state of a `StateMachine`.
+type TransitionCallback = Box<dyn FnMut(&State, &State)>;
+
 /// Represents a state machine that transitions between different states.
+///
+/// The only valid transitions are `Initial -> InProgress` and `InProgress -> Completed`.
+/// Registered callbacks cannot be cloned or compared, so the machine is not `Clone` and
+/// its equality only considers the current state and history.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::{State, StateMachine, TransitionError};
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// let mut machine = StateMachine::new();
+/// assert_eq!(machine.current(), &State::Initial);
+/// assert_eq!(
+///     machine.transition(State::Completed),
+///     Err(TransitionError { from: State::Initial, to: State::Completed })
+/// );
+///
+/// assert_eq!(machine.transition(State::InProgress), Ok(()));
+/// assert_eq!(machine.transition(State::Completed), Ok(()));
+/// assert_eq!(machine.current(), &State::Completed);
+/// assert_eq!(machine.history(), &[State::Initial, State::InProgress, State::Completed]);
+///
+/// let seen = Rc::new(RefCell::new(Vec::new()));
+/// let log = Rc::clone(&seen);
+/// let mut machine = StateMachine::new();
+/// machine.on_transition(move |from, to| log.borrow_mut().push((*from, *to)));
+/// machine.transition(State::InProgress).unwrap();
+/// assert!(machine.transition(State::Initial).is_err());
+/// assert_eq!(machine.history().len(), 2);
+/// assert_eq!(*seen.borrow(), vec![(State::Initial, State::InProgress)]);
+/// ```
 pub struct StateMachine {
     /// The current state of the state machine.
     current_state: State,
+    /// Every state the machine has been in, oldest first.
+    history: Vec<State>,
+    /// Callbacks invoked with the old and new state after each successful transition.
+    callbacks: Vec<TransitionCallback>,
+}
+
+impl StateMachine {
+    /// Creates a state machine in the `Initial` state.
+    pub fn new() -> Self {
+        StateMachine {
+            current_state: State::Initial,
+            history: vec![State::Initial],
+            callbacks: Vec::new(),
+        }
+    }
+
+    /// Returns the current state.
+    pub fn current(&self) -> &State {
+        &self.current_state
+    }
+
+    /// Returns every state the machine has been in, starting with `Initial`.
+    pub fn history(&self) -> &[State] {
+        &self.history
+    }
+
+    /// Registers a callback invoked with the old and new state after each successful transition.
+    pub fn on_transition(&mut self, cb: impl FnMut(&State, &State) + 'static) {
+        self.callbacks.push(Box::new(cb));
+    }
+
+    /// Moves to a new state if the transition is allowed.
+    pub fn transition(&mut self, to: State) -> Result<(), TransitionError> {
+        match (self.current_state, to) {
+            (from @ State::Initial, State::InProgress)
+            | (from @ State::InProgress, State::Completed) => {
+                self.current_state = to;
+                self.history.push(to);
+                for callback in &mut self.callbacks {
+                    callback(&from, &to);
+                }
+                Ok(())
+            }
+            (from, to) => Err(TransitionError { from, to }),
+        }
+    }
+}
+
+impl fmt::Debug for StateMachine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StateMachine")
+            .field("current_state", &self.current_state)
+            .field("history", &self.history)
+            .field("callbacks", &self.callbacks.len())
+            .finish()
+    }
+}
+
+impl PartialEq for StateMachine {
+    /// Compares the current state and history; registered callbacks are ignored.
+    fn eq(&self, other: &StateMachine) -> bool {
+        self.current_state == other.current_state && self.history == other.history
+    }
+}
+
+impl Default for StateMachine {
+    fn default() -> Self {
+        StateMachine::new()
+    }
+}
+
+/// Measures elapsed wall-clock time that can be paused, resumed, and split into laps.
+///
+/// # Example
+///
+/// ```rust
+/// use qol_rs::structs_and_enums::Stopwatch;
+/// use std::thread::sleep;
+/// use std::time::Duration;
+///
+/// let step = Duration::from_millis(50);
+/// let mut watch = Stopwatch::start();
+/// sleep(step);
+/// let first = watch.elapsed();
+/// assert!(first >= step);
+///
+/// sleep(step);
+/// let second = watch.elapsed();
+/// assert!(second >= first + step);
+///
+/// let lap = watch.lap();
+/// assert!(lap >= second);
+/// sleep(step);
+/// assert!(watch.lap() >= step);
+///
+/// // Paused time is frozen, so it can be compared exactly.
+/// watch.pause();
+/// assert!(!watch.is_running());
+/// let paused = watch.elapsed();
+/// sleep(step);
+/// assert_eq!(watch.elapsed(), paused);
+///
+/// watch.resume();
+/// sleep(step);
+/// assert!(watch.elapsed() >= paused + step);
+///
+/// watch.pause();
+/// watch.reset();
+/// assert_eq!(watch.elapsed(), Duration::ZERO);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Stopwatch {
+    /// Time accumulated before the most recent pause.
+    accumulated: Duration,
+    /// When the stopwatch was last started or resumed, or `None` while paused.
+    running_since: Option<Instant>,
+    /// The total elapsed time when the last lap was taken.
+    last_lap: Duration,
+}
+
+impl Stopwatch {
+    /// Creates a stopwatch that starts running immediately.
+    pub fn start() -> Self {
+        Stopwatch {
+            accumulated: Duration::ZERO,
+            running_since: Some(Instant::now()),
+            last_lap: Duration::ZERO,
+        }
+    }
+
+    /// Returns the total time the stopwatch has been running.
+    pub fn elapsed(&self) -> Duration {
+        self.accumulated
+            + self
+                .running_since
+                .map_or(Duration::ZERO, |since| since.elapsed())
+    }
+
+    /// Returns the time since the previous lap, or since the start for the first lap.
+    pub fn lap(&mut self) -> Duration {
+        let elapsed = self.elapsed();
+        let lap = elapsed - self.last_lap;
+        self.last_lap = elapsed;
+        lap
+    }
+
+    /// Clears the elapsed time and laps, leaving the stopwatch running or paused as it was.
+    pub fn reset(&mut self) {
+        self.accumulated = Duration::ZERO;
+        self.last_lap = Duration::ZERO;
+        if self.running_since.is_some() {
+            self.running_since = Some(Instant::now());
+        }
+    }
+
+    /// Stops accumulating time until `resume` is called. Does nothing if already paused.
+    pub fn pause(&mut self) {
+        if let Some(since) = self.running_since.take() {
+            self.accumulated += since.elapsed();
+        }
+    }
+
+    /// Continues accumulating time after a pause. Does nothing if already running.
+    pub fn resume(&mut self) {
+        if self.running_since.is_none() {
+            self.running_since = Some(Instant::now());
+        }
+    }
+
+    /// Returns true if the stopwatch is currently accumulating time.
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_dot_of_perpendicular_vectors_is_zero() {
+        assert_eq!(Point2D::new(1.0, 0.0).dot(&Point2D::new(0.0, 1.0)), 0.0);
+    }
+
+    #[test]
+    fn point_rotation_by_full_turn_is_identity() {
+        let point = Point2D::new(2.0, -3.0);
+        let rotated = point.rotate(std::f64::consts::TAU);
+        assert!(rotated.distance_to(&point) < 1e-10);
+        assert_eq!(point.rotate_around(&point, 1.0), point);
+    }
+
+    #[test]
+    fn point_display_honours_precision() {
+        assert_eq!(
+            format!("{:.2}", Point2D::new(1.0, 2.0 / 3.0)),
+            "(1.00, 0.67)"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn point_serde_round_trip() {
+        let point = Point2D::new(1.5, -2.0);
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(serde_json::from_str::<Point2D>(&json).unwrap(), point);
+    }
+
+    fn matrix<T>(rows: Vec<Vec<T>>) -> Matrix<T> {
+        Matrix::from_rows(rows).unwrap()
+    }
+
+    #[test]
+    fn color_from_hex_accepts_all_notations() {
+        let orange = Color::new(255, 136, 0);
+        assert_eq!(Color::from_hex("#ff8800"), Ok(orange));
+        assert_eq!(Color::from_hex("FF8800"), Ok(orange));
+        assert_eq!(Color::from_hex("#f80"), Ok(orange));
+        assert_eq!(Color::from_hex("f80"), Ok(orange));
+    }
+
+    #[test]
+    fn color_from_hex_rejects_malformed_input() {
+        assert_eq!(
+            Color::from_hex("#zzzzzz"),
+            Err(ColorParseError::InvalidCharacter)
+        );
+        assert_eq!(
+            Color::from_hex("#ff88"),
+            Err(ColorParseError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn color_lerp_clamps_t() {
+        let (black, white) = (Color::new(0, 0, 0), Color::new(255, 255, 255));
+        assert_eq!(black.lerp(&white, 0.0), black);
+        assert_eq!(black.lerp(&white, 1.0), white);
+        assert_eq!(black.lerp(&white, 2.0), white);
+        assert_eq!(black.lerp(&white, -1.0), black);
+        assert_eq!(black.invert(), white);
+    }
+
+    #[test]
+    fn color_hsl_round_trip() {
+        for color in [
+            Color::new(255, 136, 0),
+            Color::new(51, 102, 153),
+            Color::new(200, 30, 220),
+        ] {
+            let (h, s, l) = color.to_hsl();
+            assert_eq!(Color::from_hsl(h, s, l), color);
+        }
+    }
+
+    #[test]
+    fn color_hsl_of_gray_has_zero_hue() {
+        assert_eq!(Color::new(128, 128, 128).to_hsl().0, 0.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn color_serde_round_trip() {
+        let orange = Color::new(255, 136, 0);
+        let json = serde_json::to_string(&orange).unwrap();
+        assert_eq!(json, r#"{"red":255,"green":136,"blue":0}"#);
+        assert_eq!(serde_json::from_str::<Color>(&json).unwrap(), orange);
+    }
+
+    #[test]
+    fn date_leap_years_follow_century_rules() {
+        assert!(is_leap_year(2024));
+        assert!(!is_leap_year(2023));
+        assert!(!is_leap_year(1900));
+        assert!(is_leap_year(2000));
+    }
+
+    #[test]
+    fn date_debug_shows_fields() {
+        assert_eq!(
+            format!("{:?}", Date::new(2024, 2, 29).unwrap()),
+            "Date { day: 29, month: 2, year: 2024 }"
+        );
+    }
+
+    #[test]
+    fn date_day_of_week() {
+        assert_eq!(
+            Date::new(2000, 1, 1).unwrap().day_of_week(),
+            Weekday::Saturday
+        );
+    }
+
+    #[test]
+    fn date_parse_iso8601_rejects_invalid_dates() {
+        assert_eq!(
+            Date::parse_iso8601("2023-02-29"),
+            Err(DateError::InvalidDay)
+        );
+        assert_eq!(
+            Date::parse_iso8601("2023-00-10"),
+            Err(DateError::InvalidMonth)
+        );
+    }
+
+    #[test]
+    fn date_add_days_crosses_leap_days() {
+        let march_first = Date::new(2024, 3, 1).unwrap();
+        assert_eq!(march_first.add_days(-1), Date::new(2024, 2, 29).unwrap());
+        assert_eq!(march_first.add_days(-366), Date::new(2023, 3, 1).unwrap());
+    }
+
+    #[test]
+    fn date_sorts_chronologically() {
+        let leap_day = Date::new(2024, 2, 29).unwrap();
+        let new_years_eve = Date::new(2023, 12, 31).unwrap();
+        let january = Date::new(2023, 1, 15).unwrap();
+        let mut dates = vec![leap_day, new_years_eve, january];
+        dates.sort();
+        assert_eq!(dates, vec![january, new_years_eve, leap_day]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn date_serde_round_trip() {
+        let leap_day = Date::new(2024, 2, 29).unwrap();
+        let json = serde_json::to_string(&leap_day).unwrap();
+        assert_eq!(json, "\"2024-02-29\"");
+        assert_eq!(serde_json::from_str::<Date>(&json).unwrap(), leap_day);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn date_deserialize_rejects_invalid_dates() {
+        assert!(serde_json::from_str::<Date>("\"2023-02-29\"").is_err());
+    }
+
+    #[test]
+    fn matrix_access_out_of_bounds() {
+        let numbered = Matrix::from_fn(2, 3, |row, col| row * 3 + col);
+        assert_eq!(numbered.get(2, 0), None);
+        assert_eq!(numbered.get(0, 3), None);
+        assert_eq!(numbered.row(2), None);
+        assert_eq!(numbered.col(2), Some(vec![&2, &5]));
+        assert_eq!(numbered.col(3), None);
+    }
+
+    #[test]
+    fn matrix_map_keeps_dimensions() {
+        let doubled = Matrix::from_fn(2, 3, |row, col| (row * 3 + col) as i64 * 2);
+        assert_eq!((doubled.rows(), doubled.columns()), (2, 3));
+        assert_eq!(doubled, matrix(vec![vec![0, 2, 4], vec![6, 8, 10]]));
+    }
+
+    #[test]
+    fn matrix_arithmetic_rejects_mismatched_dimensions() {
+        let a = matrix(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let b = matrix(vec![vec![7, 8], vec![9, 10], vec![11, 12]]);
+        assert_eq!(a.multiply(&a), Err(MatrixError::DimensionMismatch));
+        assert_eq!(a.add(&b), Err(MatrixError::DimensionMismatch));
+        assert_eq!(a.add(&a), Ok(a.scale(2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix dimensions are incompatible")]
+    fn matrix_mul_operator_panics_on_mismatch() {
+        let a = matrix(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let _ = &a * &a;
+    }
+
+    #[test]
+    fn matrix_identity_3x3() {
+        let identity: Matrix<i32> = Matrix::identity(3, 1);
+        assert_eq!(
+            identity,
+            matrix(vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 1]])
+        );
+    }
+
+    #[test]
+    fn matrix_determinant() {
+        assert_eq!(Matrix::identity(4, 1.0).determinant(), Ok(1.0));
+        let singular = matrix(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ]);
+        assert!(singular.determinant().unwrap().abs() < 1e-9);
+        assert_eq!(
+            Matrix::<f64>::new(2, 3).determinant(),
+            Err(MatrixError::DimensionMismatch)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn matrix_serde_round_trip() {
+        let a = matrix(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(serde_json::from_str::<Matrix<i32>>(&json).unwrap(), a);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn matrix_deserialize_rejects_mismatched_dimensions() {
+        let json = r#"{"rows":2,"columns":2,"data":[[1.0]]}"#;
+        assert!(serde_json::from_str::<Matrix<f64>>(json).is_err());
+    }
+}